@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use bevy_intro_screen::prelude::{*,bevy_ui::BevyIntroScreen};
+use bevy_intro_screen::prelude::{*,bevy_ui::{BevyIntroScreen,OnFailureShowFallback}};
 use std::time::Duration;
 use bevy_asset_loader::prelude::AssetCollection;
 
@@ -36,7 +36,7 @@ impl Plugin for AppPlugin {
         let preferences = IntroPreferences::builder()
             .run_at(AppState::SplashScreen)
             .transition_to(transition_to)
-            .skip_on_input(true)
+            .skip_policy(SkipPolicy::default())
             .duration(FixedDuration::new_with_duration(
                 Duration::from_millis(500000000000000),
                 transition_to,
@@ -44,9 +44,14 @@ impl Plugin for AppPlugin {
             .ui(GameIntroScreen)
             .build();
 
+        // Shown instead of the intro screen if its assets fail to load
+        let failure_manager = OnFailureShowFallback::builder()
+            .label(APP_NAME)
+            .build();
+
         let intro_plugin = IntroScreenPlugin::builder()
             .preferences(preferences)
-            .failure_manager(OnFailureContinue)
+            .failure_manager(failure_manager)
             .build();
 
         app.add_plugins(intro_plugin);
@@ -79,14 +84,21 @@ impl IntroScreenAssets for GameScreenAssets {
 }
 
 impl ShowIntroScreen for GameIntroScreen {
-    fn configure_ui<S, D, U>(&self, app: &mut App, preferences: &IntroPreferences<S, D, U>)
+    fn configure_ui<S, D, U, L>(&self, app: &mut App, preferences: &IntroPreferences<S, D, U, L>)
     where
         S: States,
         D: IntroDuration,
         U: ShowIntroScreen,
+        L: IntroLabel,
     {
         IntroAssetLoader::<GameScreenAssets>::default().configure_ui(app,preferences);
-        BevyIntroScreen::<GameScreenAssets>::default().configure_ui(app, preferences);
+        BevyIntroScreen::<GameScreenAssets>::default()
+            .with_enter_transition(IntroTransition::fade(Duration::from_millis(500)))
+            .with_exit_transition(IntroTransition::new(
+                TransitionKind::FadeThroughColor(Color::BLACK),
+                Duration::from_millis(750),
+            ))
+            .configure_ui(app, preferences);
     }
 }
 
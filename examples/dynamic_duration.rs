@@ -35,8 +35,8 @@ impl Plugin for AppPlugin {
         let preferences = IntroPreferences::builder()
             .run_at(AppState::SplashScreen)
             .transition_to(transition_to)
-            .skip_on_input(true)
-            .duration(DownloadAllAssets)
+            .skip_policy(SkipPolicy::default())
+            .duration(download_all_assets())
             .ui(GameIntroScreen)
             .build();
 
@@ -47,48 +47,30 @@ impl Plugin for AppPlugin {
 
         app.add_plugins(intro_plugin);
 
-        // Change of states is visible from the logs produced by these
-        app.add_systems(Update, (log_transitions::<DownloadState>,log_transitions::<DynamicDurationState>));
+        // Change of states is visible from the logs produced by this
+        app.add_systems(Update, log_transitions::<DynamicDurationState>);
     }
 }
 
-#[derive(Resource,Clone)]
-pub struct DownloadAllAssets;
-
-#[derive(States,EnumIter,Clone ,PartialEq , Eq , Hash , Debug,Copy,Default)]
-pub enum DownloadState {
-    #[default]
+#[derive(EnumIter,Clone,Debug,Copy)]
+pub enum Download {
     Models,
     Audio,
     UserSettings
 }
 
-impl IntroDuration for DownloadAllAssets {
-    fn configure_duration<S, D, U>(&self, app: &mut App, preferences: &IntroPreferences<S, D, U>)
-        where
-            S: States + bevy::state::state::FreelyMutableState,
-            D: IntroDuration,
-            U: ShowIntroScreen {
-        app.init_state::<DownloadState>();
-
-        let generic = GenericDynamicDuration::new(Duration::from_secs(60));
-        generic.configure_duration(app,preferences);
-
-        app.add_systems(OnEnter(IntroState::Running),download_assets);
-    }
-}
-
-fn download_assets(mut intro_next_state : ResMut<NextState<DynamicDurationState>>,mut stage_next_state : ResMut<NextState<DownloadState>>) {
-    for (index,_) in DownloadState::iter().enumerate() {
-        // Varied duration required for completion
-        std::thread::sleep(Duration::from_secs(index as u64));
-
-        if let Some(state) = DownloadState::iter().nth(index + 1usize) {
-            stage_next_state.set(state);
-        }
-    }
-
-    intro_next_state.set(DynamicDurationState::Completed);
+// Every download runs on a background thread, so the intro screen keeps rendering in the meantime
+fn download_all_assets() -> TaskDuration {
+    Download::iter().enumerate().fold(TaskDuration::new(Duration::from_secs(60)),|duration,(index,download)| {
+        duration.with_blocking_task(format!("{download:?}"),move |progress| {
+            // Varied duration required for completion
+            for step in 1..=10 {
+                std::thread::sleep(Duration::from_millis(index as u64 * 100));
+                progress.set(step as f32 / 10.0);
+            }
+            Ok(())
+        })
+    })
 }
 
 // ---- Same as egui example ----
@@ -98,11 +80,12 @@ fn setup(contexts: EguiContexts,mut commands : Commands) {
 }
 
 impl ShowIntroScreen for GameIntroScreen {
-    fn configure_ui<S, D, U>(&self, app: &mut App, preferences: &IntroPreferences<S, D, U>)
+    fn configure_ui<S, D, U, L>(&self, app: &mut App, preferences: &IntroPreferences<S, D, U, L>)
     where
         S: States,
         D: IntroDuration,
         U: ShowIntroScreen,
+        L: IntroLabel,
     {
         let egui = EguiIntroScreen::builder()
         .label(LABEL.into())
@@ -110,6 +93,7 @@ impl ShowIntroScreen for GameIntroScreen {
             "../assets/images/app_logo.png"
         ))
         .background(bevy_egui::egui::include_image!("../assets/images/blue_background.png"))
+        .progress_bar(ProgressBarStyle::default())
         .build();
 
     egui.configure_ui(app, preferences);
@@ -32,7 +32,7 @@ impl Plugin for AppPlugin {
         let preferences = IntroPreferences::builder()
             .run_at(AppState::SplashScreen)
             .transition_to(transition_to)
-            .skip_on_input(true)
+            .skip_policy(SkipPolicy::default())
             .duration(FixedDuration::new_with_duration(
                 Duration::from_millis(5000),
                 transition_to,
@@ -55,11 +55,12 @@ fn setup(contexts: EguiContexts,mut commands : Commands) {
 }
 
 impl ShowIntroScreen for GameIntroScreen {
-    fn configure_ui<S, D, U>(&self, app: &mut App, preferences: &IntroPreferences<S, D, U>)
+    fn configure_ui<S, D, U, L>(&self, app: &mut App, preferences: &IntroPreferences<S, D, U, L>)
     where
         S: States,
         D: IntroDuration,
         U: ShowIntroScreen,
+        L: IntroLabel,
     {
         let egui = EguiIntroScreen::builder()
         .label(LABEL.into())
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use bevy_intro_screen::prelude::{*,egui::EguiIntroScreen};
+use bevy_egui::EguiContexts;
+use std::time::Duration;
+fn main() {    
+    App::new()
+        .add_plugins(AppPlugin)
+        .run();
+}
+
+const APP_NAME: &'static str = "My game";
+
+pub struct AppPlugin;
+impl Plugin for AppPlugin {
+    fn build(&self,app: &mut App) {
+        app.add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: String::from(APP_NAME),
+                ..Default::default()
+            }),
+            close_when_requested: true,
+            ..Default::default()
+        }));
+
+        app.add_systems(Startup, setup);
+
+        app.init_state::<AppState>();
+
+        // Main Difference
+        let sequence = IntroSequence::new([
+            slide("MY STUDIO",false),
+            slide("MADE WITH BEVY",true),
+            slide(APP_NAME,true),
+        ]);
+
+        let preferences = IntroPreferences::builder()
+            .run_at(AppState::SplashScreen)
+            .transition_to(AppState::GameMenu)
+            .skip_policy(SkipPolicy::default())
+            .duration(sequence.clone())
+            .ui(sequence)
+            .build();
+
+        let intro_plugin = IntroScreenPlugin::builder()
+            .preferences(preferences)
+            .failure_manager(OnFailureContinue)
+            .build();
+
+        app.add_plugins(intro_plugin);
+    }
+}
+
+fn slide(label : &'static str,skippable : bool) -> IntroSlide<EguiIntroScreen<'static>> {
+    let ui = EguiIntroScreen::builder()
+        .label(label.into())
+        .icon(bevy_egui::egui::include_image!(
+            "../assets/images/app_logo.png"
+        ))
+        .background(bevy_egui::egui::include_image!("../assets/images/blue_background.png"))
+        .enter_transition(IntroTransition::fade(Duration::from_millis(400)))
+        .cross_fade(true)
+        .build();
+
+    IntroSlide::builder()
+        .ui(ui)
+        .duration(Duration::from_millis(2000))
+        .skippable(skippable)
+        .build()
+}
+
+fn setup(contexts: EguiContexts,mut commands : Commands) {
+    egui_extras::install_image_loaders(contexts.ctx());
+    commands.spawn(Camera2dBundle::default());
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+pub enum AppState {
+    #[default]
+    SplashScreen,
+
+    GameMenu,
+}
@@ -16,8 +16,18 @@ mod splash_screen;
 
 ///
 pub mod prelude {
+    pub use crate::splash_screen::events::*;
+    pub use crate::splash_screen::label::*;
+    pub use crate::splash_screen::loading_screen::*;
+    pub use crate::splash_screen::progress::*;
+    pub use crate::splash_screen::reason::*;
+    pub use crate::splash_screen::restart::OnIntroStart;
+    pub use crate::splash_screen::sequence::*;
+    pub use crate::splash_screen::skip_screen::{SkipHoldProgress, SkipInput, SkipPolicy, UnskippableWindow};
     pub use crate::splash_screen::state::*;
     pub use crate::splash_screen::tick::*;
+    pub use crate::splash_screen::time::{IntroTime, IntroTimeSource};
+    pub use crate::splash_screen::transition::*;
     pub use crate::splash_screen::*;
 
     pub use crate::splash_screen::ui::*;
@@ -1,60 +1,134 @@
-use bevy::prelude::*;
-use crate::prelude::{IntroScreenAssets,IntroState,ShowIntroScreen};
-use std::marker::PhantomData;
+use bevy::{prelude::*, render::{render_asset::RenderAssetUsages,texture::{CompressedImageFormats,ImageSampler,ImageType}}, state::state::FreelyMutableState, ui::UiSystem};
+use crate::prelude::{FailureDialog,FailureDialogButton,FailureDialogChoice,IntroFailure,IntroFailureManager,IntroFailureSchedules,IntroScreenAssets,IntroLabel,IntroResource,IntroProgress,IntroTime,IntroState,ShowIntroScreen,IntroTransition,ProgressBarPosition,ProgressBarStyle,TransitionKind,TransitionProgress,SlideDirection,SkipHoldProgress,is_running_labeled};
+use super::{events::IntroClock,ui::FailureDialogOpen,skip_screen::HOLD_TO_SKIP_LABEL,transition::IntroTransitions};
+use std::{borrow::Cow,marker::PhantomData,time::Duration};
 
 /// Represents an intro screen.
-pub struct BevyIntroScreen<T>(PhantomData<T>) where T : IntroScreenAssets;
+pub struct BevyIntroScreen<T> where T : IntroScreenAssets {
+    enter_transition : Option<IntroTransition>,
+    exit_transition : Option<IntroTransition>,
+    progress_bar : Option<ProgressBarStyle>,
+    _phantom : PhantomData<T>,
+}
 
 impl<T> Default for BevyIntroScreen<T> where T : IntroScreenAssets {
     fn default() -> Self {
-        Self(PhantomData::<T>)
+        Self {
+            enter_transition : None,
+            exit_transition : None,
+            progress_bar : None,
+            _phantom : PhantomData::<T>,
+        }
+    }
+}
+
+impl<T> BevyIntroScreen<T> where T : IntroScreenAssets {
+    /// Animates the screen in using the given transition once it starts running.
+    pub fn with_enter_transition(mut self,transition : IntroTransition) -> Self {
+        self.enter_transition = Some(transition);
+        self
+    }
+
+    /// Animates the screen out using the given transition before switching to [super::IntroPreferences::transition_to].
+    pub fn with_exit_transition(mut self,transition : IntroTransition) -> Self {
+        self.exit_transition = Some(transition);
+        self
+    }
+
+    /// Shows the [IntroProgress] of the intro as a bar, or an animated bar while it is unknown.
+    pub fn with_progress_bar(mut self,style : ProgressBarStyle) -> Self {
+        self.progress_bar = Some(style);
+        self
     }
 }
 
 impl<T> ShowIntroScreen for BevyIntroScreen<T>
 where T : IntroScreenAssets
 {
-    fn configure_ui<S, D, U>(
+    fn configure_ui<S, D, U, L>(
         &self,
         app: &mut bevy::prelude::App,
-        _: &super::IntroPreferences<S, D, U>,
+        _: &super::IntroPreferences<S, D, U, L>,
     ) where
         S: bevy::prelude::States,
         D: super::IntroDuration,
         U: ShowIntroScreen,
+        L: IntroLabel,
     {
-        app.add_systems(OnEnter(IntroState::Running),spawn_ui::<T>)
-            .add_systems(OnExit(IntroState::Running),despawn_ui::<T>);
+        app.insert_resource(IntroTransitions::<L>::new(self.enter_transition,self.exit_transition));
+
+        if let Some(style) = &self.progress_bar {
+            app.insert_resource(IntroResource::<_,L>::new(style.clone()));
+        }
+
+        app.add_systems(OnEnter(L::State::from(IntroState::Running)),spawn_ui::<T,L>)
+            .add_systems(OnExit(L::State::from(IntroState::Running)),despawn_ui::<L>)
+            .add_systems(Update,(animate_transition::<L>,fade_content::<L>,update_progress_bar::<L>).run_if(is_running_labeled::<L>))
+            // The hold is only updated in `Update`, the indicator follows before the layout is computed
+            .add_systems(PostUpdate,update_skip_indicator::<L>.before(UiSystem::Layout).run_if(is_running_labeled::<L>));
     }
 }
 
-/// A marker component for entities representing the background of a UI element.
+/// A marker component for entities representing the background of the UI of the intro labelled `L`.
+#[derive(Component, Clone)]
+pub struct BevyBackgroundMarker<L : IntroLabel = ()>(PhantomData<L>);
+
+/// A marker component for entities representing icons within the UI of the intro labelled `L`.
+#[derive(Component, Clone)]
+pub struct BevyIconMarker<L : IntroLabel = ()>(PhantomData<L>);
+
+/// A marker component for entities representing text labels in the UI of the intro labelled `L`.
 #[derive(Component, Clone)]
-pub struct BevyBackgroundMarker;
+pub struct BevyLabelMarker<L : IntroLabel = ()>(PhantomData<L>);
 
-/// A marker component for entities representing icons within a UI.
+/// A marker component for the entity showing the progress of holding down a skip input.
+///
+/// Only spawned if [crate::prelude::SkipPolicy::hold] is set.
 #[derive(Component, Clone)]
-pub struct BevyIconMarker;
+pub struct BevySkipIndicatorMarker<L : IntroLabel = ()>(PhantomData<L>);
 
-/// A marker component for entities representing text labels in a UI.
+/// A marker component for the bar filled according to [SkipHoldProgress::fraction].
 #[derive(Component, Clone)]
-pub struct BevyLabelMarker;
+pub struct BevySkipIndicatorFillMarker<L : IntroLabel = ()>(PhantomData<L>);
+
+/// A marker component for the track of the progress bar of the intro labelled `L`.
+///
+/// Only spawned if [BevyIntroScreen::with_progress_bar] is used.
+#[derive(Component, Clone)]
+pub struct BevyProgressBarMarker<L : IntroLabel = ()>(PhantomData<L>);
+
+/// A marker component for the bar filled according to [IntroProgress::fraction].
+#[derive(Component, Clone)]
+pub struct BevyProgressBarFillMarker<L : IntroLabel = ()>(PhantomData<L>);
+
+/// A marker component for the text showing [IntroProgress::message] above the progress bar.
+#[derive(Component, Clone)]
+pub struct BevyProgressBarMessageMarker<L : IntroLabel = ()>(PhantomData<L>);
 
 /// An internal marker component for UI-related entities.
 #[derive(Component, Clone)]
-struct BevyUiMarker;
+struct BevyUiMarker<L : IntroLabel>(PhantomData<L>);
+
+/// An internal marker component for the solid colour overlay used by [TransitionKind::FadeThroughColor].
+#[derive(Component, Clone)]
+struct BevyTransitionOverlayMarker<L : IntroLabel>(PhantomData<L>);
 
 
 // the zindex the label an dicon is spawned on so that more entities can be spawned 'below' and 'above' them
 pub const BEVY_INTRO_SCREEN_CONTENT_ZINDEX : ZIndex = ZIndex::Local(10);
 
-fn despawn_ui<T>(mut commands : Commands,query : Query<Entity,With<BevyUiMarker>>) where T : IntroScreenAssets {
+fn despawn_ui<L>(mut commands : Commands,query : Query<Entity,With<BevyUiMarker<L>>>) where L : IntroLabel {
     commands.entity(query.single()).despawn_recursive();
 }
 
-fn spawn_ui<T>(mut commands : Commands,assets : Res<T>) where T : IntroScreenAssets {
+fn spawn_ui<T,L>(
+    mut commands : Commands,
+    assets : Res<T>,
+    hold : Option<Res<SkipHoldProgress<L>>>,
+    progress_bar : Option<Res<IntroResource<ProgressBarStyle,L>>>,
+) where T : IntroScreenAssets,L : IntroLabel {
     commands.spawn((
-        BevyUiMarker,
+        BevyUiMarker::<L>(PhantomData),
         NodeBundle {
             style : Style {
                 height : Val::Percent(100.0),
@@ -65,18 +139,28 @@ fn spawn_ui<T>(mut commands : Commands,assets : Res<T>) where T : IntroScreenAss
         }
     )).with_children(|layout| {
         if let Some(background) = assets.background() {
-            layout.spawn(BevyBackgroundMarker::bundle(background))
-                .with_children(|builder| main_content::<T>(builder,assets));
+            layout.spawn(BevyBackgroundMarker::<L>::bundle(background))
+                .with_children(|builder| main_content::<T,L>(builder,assets));
         } else {
-            main_content::<T>(layout,assets);
+            main_content::<T,L>(layout,assets);
         }
+
+        if hold.is_some() {
+            BevySkipIndicatorMarker::<L>::spawn(layout);
+        }
+
+        if let Some(style) = progress_bar {
+            BevyProgressBarMarker::<L>::spawn(layout,&style);
+        }
+
+        layout.spawn(BevyTransitionOverlayMarker::<L>::bundle());
     });
 }
 
-impl BevyBackgroundMarker {
+impl<L> BevyBackgroundMarker<L> where L : IntroLabel {
     fn bundle(background : &Handle<Image>) -> impl Bundle {
         (
-            BevyBackgroundMarker,
+            Self(PhantomData),
             ImageBundle {
                 image : UiImage {
                     texture : background.clone(),
@@ -93,10 +177,154 @@ impl BevyBackgroundMarker {
     }
 }
 
-impl BevyIconMarker {
+impl<L> BevySkipIndicatorMarker<L> where L : IntroLabel {
+    fn spawn(layout : &mut ChildBuilder<'_>) {
+        layout.spawn((
+            Self(PhantomData),
+            NodeBundle {
+                style : Style {
+                    position_type : PositionType::Absolute,
+                    bottom : Val::Px(32.0),
+                    width : Val::Percent(100.0),
+                    flex_direction : FlexDirection::Column,
+                    align_items : AlignItems::Center,
+                    ..Default::default()
+                },
+                // Only shown while a skip input is held down
+                visibility : Visibility::Hidden,
+                z_index : BEVY_INTRO_SCREEN_CONTENT_ZINDEX,
+                ..Default::default()
+            }
+        )).with_children(|indicator| {
+            indicator.spawn(TextBundle::from_section(
+                HOLD_TO_SKIP_LABEL,
+                TextStyle {
+                    font_size : 18.0,
+                    ..Default::default()
+                }
+            ));
+            indicator.spawn(
+                NodeBundle {
+                    style : Style {
+                        width : Val::Px(200.0),
+                        height : Val::Px(6.0),
+                        margin : UiRect::top(Val::Px(8.0)),
+                        ..Default::default()
+                    },
+                    background_color : BackgroundColor(Color::srgba(1.0,1.0,1.0,0.25)),
+                    ..Default::default()
+                }
+            ).with_children(|track| {
+                track.spawn((
+                    BevySkipIndicatorFillMarker::<L>(PhantomData),
+                    NodeBundle {
+                        style : Style {
+                            width : Val::Percent(0.0),
+                            height : Val::Percent(100.0),
+                            ..Default::default()
+                        },
+                        background_color : BackgroundColor(Color::WHITE),
+                        ..Default::default()
+                    }
+                ));
+            });
+        });
+    }
+}
+
+impl<L> BevyProgressBarMarker<L> where L : IntroLabel {
+    fn spawn(layout : &mut ChildBuilder<'_>,style : &ProgressBarStyle) {
+        let margin = Val::Px(*style.margin());
+        let (top,bottom) = match style.position() {
+            ProgressBarPosition::Top => (margin,Val::Auto),
+            ProgressBarPosition::Bottom => (Val::Auto,margin),
+        };
+
+        layout.spawn(
+            NodeBundle {
+                style : Style {
+                    position_type : PositionType::Absolute,
+                    top,
+                    bottom,
+                    width : Val::Percent(100.0),
+                    flex_direction : FlexDirection::Column,
+                    align_items : AlignItems::Center,
+                    ..Default::default()
+                },
+                z_index : BEVY_INTRO_SCREEN_CONTENT_ZINDEX,
+                ..Default::default()
+            }
+        ).with_children(|row| {
+            row.spawn((
+                BevyProgressBarMessageMarker::<L>(PhantomData),
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size : 18.0,
+                        color : *style.foreground(),
+                        ..Default::default()
+                    }
+                ).with_style(Style {
+                    margin : UiRect::bottom(Val::Px(8.0)),
+                    ..Default::default()
+                })
+            ));
+            row.spawn((
+                Self(PhantomData),
+                NodeBundle {
+                    style : Style {
+                        width : Val::Px(style.size().x),
+                        height : Val::Px(style.size().y),
+                        // The fill moves through the track while the progress is unknown
+                        overflow : Overflow::clip(),
+                        ..Default::default()
+                    },
+                    background_color : BackgroundColor(*style.background()),
+                    ..Default::default()
+                }
+            )).with_children(|track| {
+                track.spawn((
+                    BevyProgressBarFillMarker::<L>(PhantomData),
+                    NodeBundle {
+                        style : Style {
+                            position_type : PositionType::Absolute,
+                            width : Val::Percent(0.0),
+                            height : Val::Percent(100.0),
+                            ..Default::default()
+                        },
+                        background_color : BackgroundColor(*style.foreground()),
+                        ..Default::default()
+                    }
+                ));
+            });
+        });
+    }
+}
+
+impl<L> BevyTransitionOverlayMarker<L> where L : IntroLabel {
+    fn bundle() -> impl Bundle {
+        (
+            Self(PhantomData),
+            NodeBundle {
+                style : Style {
+                    position_type : PositionType::Absolute,
+                    height : Val::Percent(100.0),
+                    width : Val::Percent(100.0),
+                    ..Default::default()
+                },
+                background_color : BackgroundColor(Color::NONE),
+                // Drawn above the background and the main content
+                z_index : ZIndex::Local(i32::MAX),
+                ..Default::default()
+            }
+        )
+    }
+}
+
+impl<L> BevyIconMarker<L> where L : IntroLabel {
     fn bundle(icon : &Handle<Image>) -> impl Bundle {
         (
-            Self,
+            Self(PhantomData),
             ImageBundle {
                 image : UiImage {
                     texture : icon.clone(),
@@ -114,10 +342,10 @@ impl BevyIconMarker {
     }
 }
 
-impl BevyLabelMarker {
+impl<L> BevyLabelMarker<L> where L : IntroLabel {
     fn bundle(label : &str) -> impl Bundle {
         (
-            Self,
+            Self(PhantomData),
             TextBundle {
                 text : Text::from_section(
                     label,
@@ -133,7 +361,7 @@ impl BevyLabelMarker {
     }
 }
 
-fn main_content<T>(builder : &mut ChildBuilder<'_>,assets : Res<T>) where T: IntroScreenAssets {
+fn main_content<T,L>(builder : &mut ChildBuilder<'_>,assets : Res<T>) where T: IntroScreenAssets,L : IntroLabel {
     builder.spawn((
         NodeBundle {
             style :  Style {
@@ -153,7 +381,7 @@ fn main_content<T>(builder : &mut ChildBuilder<'_>,assets : Res<T>) where T: Int
             ..Default::default()
         }
     )).with_children(|layout| {
-        layout.spawn(BevyIconMarker::bundle(assets.icon()));
+        layout.spawn(BevyIconMarker::<L>::bundle(assets.icon()));
         layout.spawn(
             NodeBundle {
                 style : Style {
@@ -163,6 +391,512 @@ fn main_content<T>(builder : &mut ChildBuilder<'_>,assets : Res<T>) where T: Int
                 ..Default::default()
             }
         );
-        layout.spawn(BevyLabelMarker::bundle(&assets.label()));
+        layout.spawn(BevyLabelMarker::<L>::bundle(&assets.label()));
     });
-}
\ No newline at end of file
+}
+// The alpha of the intro screen's content before it was faded, so fading scales it instead of
+// overwriting it and it can be restored once the fade has finished
+#[derive(Component,Clone)]
+struct BevyFadeOrigin {
+    image : Option<f32>,
+    background : Option<f32>,
+    text : Vec<f32>,
+}
+
+impl BevyFadeOrigin {
+    fn capture(image : Option<&UiImage>,background : Option<&BackgroundColor>,text : Option<&Text>) -> Self {
+        Self {
+            image : image.map(|image| image.color.alpha()),
+            background : background.map(|background| background.0.alpha()),
+            text : text.iter().flat_map(|text| &text.sections).map(|section| section.style.color.alpha()).collect(),
+        }
+    }
+
+    // Only writes the components whose alpha changes, so the text layout isn't redone every frame
+    fn fade(&self,image : Option<Mut<UiImage>>,background : Option<Mut<BackgroundColor>>,text : Option<Mut<Text>>,visibility : f32) {
+        if let (Some(mut image),Some(alpha)) = (image,self.image) {
+            if image.color.alpha() != alpha * visibility {
+                image.color.set_alpha(alpha * visibility);
+            }
+        }
+
+        if let (Some(mut background),Some(alpha)) = (background,self.background) {
+            let color = background.0.with_alpha(alpha * visibility);
+            background.set_if_neq(BackgroundColor(color));
+        }
+
+        if let Some(mut text) = text {
+            let faded = |(section,alpha) : (&TextSection,&f32)| section.style.color.alpha() == alpha * visibility;
+            if !text.sections.iter().zip(&self.text).all(faded) {
+                for (section,alpha) in text.sections.iter_mut().zip(&self.text) {
+                    section.style.color.set_alpha(alpha * visibility);
+                }
+            }
+        }
+    }
+}
+
+type FadedContent<'a> = (
+    Option<&'a mut UiImage>,
+    Option<&'a mut BackgroundColor>,
+    Option<&'a mut Text>,
+    Option<&'a BevyFadeOrigin>,
+);
+
+fn fade_content<L : IntroLabel>(
+    mut commands : Commands,
+    progress : Res<TransitionProgress<L>>,
+    roots : Query<Entity,With<BevyUiMarker<L>>>,
+    mut content : Query<FadedContent,Without<BevyTransitionOverlayMarker<L>>>,
+    children : Query<&Children>,
+) {
+    let Ok(root) = roots.get_single() else {
+        return;
+    };
+
+    let fading = matches!(progress.transition().map(|transition| *transition.kind()),Some(TransitionKind::Fade));
+    for entity in children.iter_descendants(root) {
+        let Ok((image,background,text,origin)) = content.get_mut(entity) else {
+            continue;
+        };
+
+        match (fading,origin) {
+            (true,Some(origin)) => origin.clone().fade(image,background,text,progress.visibility()),
+            (true,None) => {
+                let origin = BevyFadeOrigin::capture(image.as_deref(),background.as_deref(),text.as_deref());
+                origin.fade(image,background,text,progress.visibility());
+                commands.entity(entity).insert(origin);
+            }
+            // The fade has finished, so the original alpha is restored once
+            (false,Some(origin)) => {
+                origin.clone().fade(image,background,text,1.0);
+                commands.entity(entity).remove::<BevyFadeOrigin>();
+            }
+            (false,None) => {}
+        }
+    }
+}
+
+fn animate_transition<L : IntroLabel>(
+    progress : Res<TransitionProgress<L>>,
+    mut roots : Query<(&mut Style,&mut Transform),With<BevyUiMarker<L>>>,
+    mut overlays : Query<&mut BackgroundColor,With<BevyTransitionOverlayMarker<L>>>,
+) {
+    let visibility = progress.visibility();
+    let kind = progress.transition().map(|transition| *transition.kind());
+
+    let Ok((mut style,mut transform)) = roots.get_single_mut() else {
+        return;
+    };
+
+    let overlay = match kind {
+        Some(TransitionKind::FadeThroughColor(color)) => color.with_alpha(color.alpha() * (1.0 - visibility)),
+        _ => Color::NONE,
+    };
+
+    for mut background in overlays.iter_mut() {
+        background.set_if_neq(BackgroundColor(overlay));
+    }
+
+    let offset = Val::Percent((1.0 - visibility) * 100.0);
+    let (left,top) = match kind {
+        Some(TransitionKind::Slide(SlideDirection::Left)) => (-offset,Val::Auto),
+        Some(TransitionKind::Slide(SlideDirection::Right)) => (offset,Val::Auto),
+        Some(TransitionKind::Slide(SlideDirection::Up)) => (Val::Auto,-offset),
+        Some(TransitionKind::Slide(SlideDirection::Down)) => (Val::Auto,offset),
+        _ => (Val::Auto,Val::Auto),
+    };
+
+    if style.left != left || style.top != top {
+        style.left = left;
+        style.top = top;
+    }
+
+    let scale = match kind {
+        Some(TransitionKind::Scale) => Vec3::splat(visibility),
+        _ => Vec3::ONE,
+    };
+
+    transform.set_if_neq(Transform { scale, ..*transform });
+}
+
+fn update_skip_indicator<L : IntroLabel>(
+    hold : Option<Res<SkipHoldProgress<L>>>,
+    mut indicators : Query<&mut Visibility,With<BevySkipIndicatorMarker<L>>>,
+    mut fills : Query<&mut Style,With<BevySkipIndicatorFillMarker<L>>>,
+) {
+    let Some(hold) = hold else {
+        return;
+    };
+
+    let visibility = match hold.is_holding() {
+        true => Visibility::Inherited,
+        false => Visibility::Hidden,
+    };
+
+    for mut indicator in indicators.iter_mut() {
+        indicator.set_if_neq(visibility);
+    }
+
+    let width = Val::Percent(hold.fraction() * 100.0);
+    for mut fill in fills.iter_mut() {
+        if fill.width != width {
+            fill.width = width;
+        }
+    }
+}
+
+// How long the fill takes to move through the track while the progress is unknown
+const INDETERMINATE_PROGRESS_PERIOD : f32 = 1.5;
+
+fn update_progress_bar<L : IntroLabel>(
+    progress : Res<IntroProgress<L>>,
+    time : Res<IntroTime<L>>,
+    mut fills : Query<&mut Style,With<BevyProgressBarFillMarker<L>>>,
+    mut messages : Query<&mut Text,With<BevyProgressBarMessageMarker<L>>>,
+) {
+    let message = progress.message().unwrap_or_default();
+    for mut text in messages.iter_mut() {
+        if text.sections[0].value != message {
+            text.sections[0].value = message.to_owned();
+        }
+    }
+
+    let (left,width) = match progress.fraction() {
+        Some(fraction) => (0.0,fraction * 100.0),
+        None => {
+            let cycle = (time.elapsed().as_secs_f32() % INDETERMINATE_PROGRESS_PERIOD) / INDETERMINATE_PROGRESS_PERIOD;
+            (cycle * 125.0 - 25.0,25.0)
+        }
+    };
+
+    let (left,width) = (Val::Percent(left),Val::Percent(width));
+    for mut fill in fills.iter_mut() {
+        if fill.left != left || fill.width != width {
+            fill.left = left;
+            fill.width = width;
+        }
+    }
+}
+
+/// Shows a built-in fallback screen when the intro fails, for example because its
+/// [IntroScreenAssets] could not be loaded, then continues to [super::IntroPreferences::transition_to].
+///
+/// The fallback screen is made of a text label and an image on a solid colour background. Unless
+/// another image is set, the image is embedded in the crate, so the screen doesn't depend on any
+/// asset that could fail to load itself. It is shown for whatever is left of
+/// [OnFailureShowFallback::duration], counted from the start of the intro.
+#[derive(Clone,Debug)]
+#[bon::builder]
+pub struct OnFailureShowFallback {
+    /// The text shown below the image.
+    #[builder(into)]
+    label : Cow<'static,str>,
+    /// The colour the screen is filled with.
+    #[builder(default = Color::BLACK)]
+    background : Color,
+    /// The colour of the image and the label.
+    #[builder(default = Color::WHITE)]
+    foreground : Color,
+    /// The image shown above the label, tinted using the foreground colour. Defaults to the
+    /// warning sign embedded in the crate.
+    image : Option<Handle<Image>>,
+    /// How long the intro lasts, including the time before the failure.
+    #[builder(default = Duration::from_millis(1500))]
+    duration : Duration,
+    /// Whether the [IntroFailure::reason] is shown below the label, if it is known.
+    #[builder(default)]
+    show_reason : bool,
+}
+
+/// A marker component for the root entity of the fallback screen shown by [OnFailureShowFallback].
+#[derive(Component, Clone)]
+pub struct BevyFallbackMarker<L : IntroLabel = ()>(PhantomData<L>);
+
+// How long the fallback screen is still shown for
+#[derive(Resource)]
+struct FallbackCountdown<L : IntroLabel> {
+    timer : Timer,
+    label : PhantomData<L>,
+}
+
+// The size the image of the fallback screen is shown at, in pixels
+const FALLBACK_IMAGE_SIZE : f32 = 64.0;
+
+// The default image of the fallback screen, a white warning sign
+const FALLBACK_IMAGE : &[u8] = include_bytes!("fallback.png");
+
+impl IntroFailureManager for OnFailureShowFallback {
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
+    where
+        S: States + FreelyMutableState,
+        D: super::IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        app.insert_resource(IntroResource::<_,L>::new(self.clone()))
+            .insert_resource(FallbackCountdown::<L> {
+                timer : Timer::new(self.duration,TimerMode::Once),
+                label : PhantomData,
+            })
+            .add_systems(schedules.enter(),spawn_fallback::<L>)
+            .add_systems(Update,continue_after_fallback::<S,D,U,L>.in_set(schedules.set()))
+            .add_systems(schedules.exit(),despawn_fallback::<L>);
+    }
+}
+
+impl OnFailureShowFallback {
+    fn default_image() -> Image {
+        Image::from_buffer(
+            FALLBACK_IMAGE,
+            ImageType::Extension("png"),
+            CompressedImageFormats::NONE,
+            true,
+            ImageSampler::Default,
+            RenderAssetUsages::RENDER_WORLD,
+        ).expect("the embedded fallback image is a valid png")
+    }
+}
+
+fn spawn_fallback<L : IntroLabel>(
+    mut commands : Commands,
+    mut images : ResMut<Assets<Image>>,
+    mut countdown : ResMut<FallbackCountdown<L>>,
+    fallback : Res<IntroResource<OnFailureShowFallback,L>>,
+    failure : Res<IntroFailure<L>>,
+    clock : IntroClock<L>,
+) {
+    countdown.timer = Timer::new(fallback.duration.saturating_sub(clock.elapsed()),TimerMode::Once);
+
+    let image = fallback.image.clone().unwrap_or_else(|| images.add(OnFailureShowFallback::default_image()));
+
+    commands.spawn((
+        BevyFallbackMarker::<L>(PhantomData),
+        NodeBundle {
+            style : Style {
+                height : Val::Percent(100.0),
+                width : Val::Percent(100.0),
+                display : Display::Flex,
+                align_items : AlignItems::Center,
+                justify_content : JustifyContent::Center,
+                flex_direction : FlexDirection::Column,
+                ..Default::default()
+            },
+            background_color : BackgroundColor(fallback.background),
+            ..Default::default()
+        }
+    )).with_children(|layout| {
+        layout.spawn(ImageBundle {
+            image : UiImage {
+                texture : image,
+                color : fallback.foreground,
+                ..Default::default()
+            },
+            style : Style {
+                width : Val::Px(FALLBACK_IMAGE_SIZE),
+                height : Val::Px(FALLBACK_IMAGE_SIZE),
+                margin : UiRect::bottom(Val::Px(25.0)),
+                ..Default::default()
+            },
+            z_index : BEVY_INTRO_SCREEN_CONTENT_ZINDEX,
+            ..Default::default()
+        });
+        layout.spawn(TextBundle {
+            text : Text::from_section(
+                fallback.label.clone(),
+                TextStyle {
+                    font_size : 36.0,
+                    color : fallback.foreground,
+                    ..Default::default()
+                }
+            ),
+            z_index : BEVY_INTRO_SCREEN_CONTENT_ZINDEX,
+            ..Default::default()
+        });
+
+        if let Some(reason) = failure.reason().filter(|_| fallback.show_reason) {
+            layout.spawn(TextBundle {
+                text : Text::from_section(
+                    reason.to_string(),
+                    TextStyle {
+                        font_size : 18.0,
+                        color : fallback.foreground.with_alpha(0.75),
+                        ..Default::default()
+                    }
+                ),
+                style : Style {
+                    margin : UiRect::top(Val::Px(12.0)),
+                    ..Default::default()
+                },
+                z_index : BEVY_INTRO_SCREEN_CONTENT_ZINDEX,
+                ..Default::default()
+            });
+        }
+    });
+}
+
+fn continue_after_fallback<S,D,U,L>(
+    mut countdown : ResMut<FallbackCountdown<L>>,
+    mut next_state : ResMut<NextState<S>>,
+    preferences : Res<super::IntroPreferences<S,D,U,L>>,
+    time : Res<IntroTime<L>>,
+) where S : States + FreelyMutableState,D : super::IntroDuration,U : ShowIntroScreen,L : IntroLabel {
+    if countdown.timer.tick(time.delta()).just_finished() {
+        next_state.set(preferences.transition_to().clone());
+    }
+}
+
+fn despawn_fallback<L : IntroLabel>(mut commands : Commands,query : Query<Entity,With<BevyFallbackMarker<L>>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Shows a [FailureDialog] on top of the screen, whose buttons are pressed to leave the failure.
+#[derive(Clone, Default)]
+pub struct OnFailureShowDialog(pub FailureDialog);
+
+/// A marker component for the root entity of the dialog shown by [OnFailureShowDialog].
+#[derive(Component, Clone)]
+pub struct BevyFailureDialogMarker<L : IntroLabel = ()>(PhantomData<L>);
+
+/// A component for the buttons of the dialog shown by [OnFailureShowDialog].
+#[derive(Component, Clone)]
+pub struct BevyFailureDialogButton<L : IntroLabel = ()> {
+    /// The choice made by pressing the button.
+    pub button : FailureDialogButton,
+    label : PhantomData<L>,
+}
+
+const DIALOG_BUTTON_COLOR : Color = Color::srgb(0.25,0.25,0.25);
+const DIALOG_BUTTON_HOVERED_COLOR : Color = Color::srgb(0.35,0.35,0.35);
+
+impl IntroFailureManager for OnFailureShowDialog {
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
+    where
+        S: States + FreelyMutableState,
+        D: super::IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        self.0.configure::<S,D,U,L>(app,&schedules);
+        app.add_systems(schedules.enter(),spawn_dialog::<L>)
+            .add_systems(Update,(press_dialog_button::<L>,close_dialog::<L>).chain().in_set(schedules.set()))
+            .add_systems(schedules.exit(),despawn_dialog::<L>);
+    }
+}
+
+fn spawn_dialog<L : IntroLabel>(
+    mut commands : Commands,
+    dialog : Res<IntroResource<FailureDialog,L>>,
+    failure : Res<IntroFailure<L>>,
+) {
+    commands.spawn((
+        BevyFailureDialogMarker::<L>(PhantomData),
+        NodeBundle {
+            style : Style {
+                height : Val::Percent(100.0),
+                width : Val::Percent(100.0),
+                position_type : PositionType::Absolute,
+                align_items : AlignItems::Center,
+                justify_content : JustifyContent::Center,
+                ..Default::default()
+            },
+            background_color : BackgroundColor(Color::BLACK.with_alpha(0.6)),
+            z_index : ZIndex::Global(i32::MAX - 1),
+            ..Default::default()
+        }
+    )).with_children(|backdrop| {
+        backdrop.spawn(NodeBundle {
+            style : Style {
+                flex_direction : FlexDirection::Column,
+                align_items : AlignItems::Center,
+                padding : UiRect::all(Val::Px(24.0)),
+                row_gap : Val::Px(12.0),
+                max_width : Val::Percent(80.0),
+                ..Default::default()
+            },
+            background_color : BackgroundColor(Color::srgb(0.12,0.12,0.12)),
+            ..Default::default()
+        }).with_children(|panel| {
+            panel.spawn(TextBundle::from_section(
+                dialog.title().clone(),
+                TextStyle { font_size : 28.0,color : Color::WHITE,..Default::default() }
+            ));
+
+            if let Some(message) = dialog.message() {
+                panel.spawn(TextBundle::from_section(
+                    message.clone(),
+                    TextStyle { font_size : 18.0,color : Color::WHITE,..Default::default() }
+                ));
+            }
+
+            if let Some(reason) = failure.reason().filter(|_| *dialog.show_reason()) {
+                panel.spawn(TextBundle::from_section(
+                    reason.to_string(),
+                    TextStyle { font_size : 14.0,color : Color::WHITE.with_alpha(0.6),..Default::default() }
+                ));
+            }
+
+            panel.spawn(NodeBundle {
+                style : Style {
+                    column_gap : Val::Px(12.0),
+                    margin : UiRect::top(Val::Px(12.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }).with_children(|row| {
+                for button in dialog.buttons() {
+                    row.spawn((
+                        BevyFailureDialogButton::<L> { button : *button,label : PhantomData },
+                        ButtonBundle {
+                            style : Style {
+                                padding : UiRect::axes(Val::Px(16.0),Val::Px(8.0)),
+                                ..Default::default()
+                            },
+                            background_color : BackgroundColor(DIALOG_BUTTON_COLOR),
+                            ..Default::default()
+                        }
+                    )).with_children(|button_node| {
+                        button_node.spawn(TextBundle::from_section(
+                            button.label(),
+                            TextStyle { font_size : 18.0,color : Color::WHITE,..Default::default() }
+                        ));
+                    });
+                }
+            });
+        });
+    });
+}
+
+fn press_dialog_button<L : IntroLabel>(
+    mut buttons : Query<(&Interaction,&BevyFailureDialogButton<L>,&mut BackgroundColor),Changed<Interaction>>,
+    mut choices : EventWriter<FailureDialogChoice<L>>,
+) {
+    for (interaction,button,mut color) in buttons.iter_mut() {
+        match interaction {
+            Interaction::Pressed => {
+                choices.send(FailureDialogChoice::new(button.button));
+            }
+            Interaction::Hovered => color.0 = DIALOG_BUTTON_HOVERED_COLOR,
+            Interaction::None => color.0 = DIALOG_BUTTON_COLOR,
+        }
+    }
+}
+
+// Any choice closes the dialog, even when the failure isn't left right away
+fn close_dialog<L : IntroLabel>(
+    commands : Commands,
+    open : Res<FailureDialogOpen<L>>,
+    query : Query<Entity,With<BevyFailureDialogMarker<L>>>,
+) {
+    if !open.0 {
+        despawn_dialog(commands,query);
+    }
+}
+
+fn despawn_dialog<L : IntroLabel>(mut commands : Commands,query : Query<Entity,With<BevyFailureDialogMarker<L>>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -1,110 +1,355 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, marker::PhantomData};
 
 use bevy::prelude::*;
 use bevy_egui::{
     egui::{
-        CentralPanel, Color32, Image as EguiImage, ImageSource, RichText, TopBottomPanel,
-        Widget,
+        emath::TSTransform, Align2, CentralPanel, Color32, Image as EguiImage, ImageSource, Label,
+        LayerId, ProgressBar, Rect, RichText, Spinner, TopBottomPanel, Ui, Vec2, Widget, Window,
     },
     EguiContexts, EguiPlugin,
 };
 
-use super::{is_running, ShowIntroScreen, IntroFailureManager};
-use crate::prelude::IntroState;
+use super::{
+    is_loading_labeled, is_running_labeled, skip_screen::HOLD_TO_SKIP_LABEL,
+    transition::IntroTransitions, ui::FailureDialogOpen, ShowIntroScreen, IntroFailureManager,
+    IntroFailureSchedules,
+};
+use crate::prelude::{
+    FailureDialog, FailureDialogChoice, IntroFailure, IntroLabel, IntroProgress, IntroResource,
+    IntroTransition, IntroState, ProgressBarPosition, ProgressBarStyle, SkipHoldProgress,
+    SlideDirection, TransitionKind, TransitionPhase, TransitionProgress,
+};
 #[derive(Resource, Clone)]
 #[bon::builder]
 pub struct EguiIntroScreen<'a> {
     background: Option<ImageSource<'a>>,
     icon: ImageSource<'a>,
     label: Cow<'a, str>,
+    /// Animates the screen in once it starts running.
+    enter_transition: Option<IntroTransition>,
+    /// Animates the screen out before switching to [super::IntroPreferences::transition_to], or to
+    /// the next slide of an [super::sequence::IntroSequence].
+    exit_transition: Option<IntroTransition>,
+    /// Whether the previous slide of an [super::sequence::IntroSequence] is faded out while
+    /// this one plays its enter transition, unless it already played its own exit transition.
+    #[builder(default)]
+    cross_fade: bool,
+    /// Shows the [IntroProgress] of the intro as a bar, or a spinner while it is unknown.
+    progress_bar: Option<ProgressBarStyle>,
 }
 
+// The screen shown by the previous slide of an `IntroSequence`, used for cross-fading
+#[derive(Resource)]
+struct PreviousEguiIntroScreen<L: IntroLabel>(Option<EguiIntroScreen<'static>>, PhantomData<L>);
+
 // allows for more complex error messages then a simple generic error message
+/// The [IntroFailure::reason] is shown below the message, if it is known.
 #[derive(Clone)]
 pub struct OnFailureShowMessage<T>(pub T)
 where
     T: Clone + AsRef<str> + Send + Sync + 'static;
 
+/// Shows a [FailureDialog] in a window, whose buttons are pressed to leave the failure.
+#[derive(Clone, Default)]
+pub struct OnFailureShowDialog(pub FailureDialog);
+
 impl<'a> ShowIntroScreen for EguiIntroScreen<'a>
 where
     'a: 'static,
 {
-    fn configure_ui<S, D, U>(
+    fn configure_ui<S, D, U, L>(
         &self,
         app: &mut bevy::prelude::App,
-        _: &super::IntroPreferences<S, D, U>,
+        _: &super::IntroPreferences<S, D, U, L>,
     ) where
         S: bevy::prelude::States,
         D: super::IntroDuration,
         U: ShowIntroScreen,
+        L: IntroLabel,
     {
-        app.add_plugins(EguiPlugin);
+        // Every intro using egui shares the same plugin
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin);
+        }
+
+        app.insert_resource(IntroResource::<_, L>::new(self.clone()))
+            .insert_resource(PreviousEguiIntroScreen::<L>(None, PhantomData))
+            .insert_resource(IntroTransitions::<L>::new(
+                self.enter_transition,
+                self.exit_transition,
+            ));
 
-        app.insert_resource(self.clone());
+        // A slide of a sequence ends by going back to loading the next one
+        app.add_systems(
+            OnTransition {
+                exited: L::State::from(IntroState::Running),
+                entered: L::State::from(IntroState::Loading),
+            },
+            remember_previous_screen::<L>,
+        )
+        .add_systems(OnEnter(L::State::from(IntroState::Idle)), forget_previous_screen::<L>)
+        .add_systems(OnExit(L::State::from(IntroState::Running)), reset_layer_transform);
 
         // This is required to that as no asset loader is registered then manually change the state of the splash screen
-        app.add_systems(OnEnter(IntroState::Loading),manual_change_load_state);
+        app.add_systems(OnEnter(L::State::from(IntroState::Loading)),manual_change_load_state::<L>);
+
+        app.add_systems(
+            Update,
+            (
+                splash::<L>.run_if(is_running_labeled::<L>),
+                splash_between_slides::<L>.run_if(is_loading_labeled::<L>),
+            ),
+        );
+    }
 
-        app.add_systems(Update, splash.run_if(is_running));
+    fn configure_slide<L>(&self, commands: &mut Commands)
+    where
+        L: IntroLabel,
+    {
+        commands.insert_resource(IntroTransitions::<L>::new(
+            self.enter_transition,
+            self.exit_transition,
+        ));
     }
 }
 
-fn manual_change_load_state(mut next_state : ResMut<NextState<IntroState>>) {
-    next_state.set(IntroState::Running)
+fn manual_change_load_state<L: IntroLabel>(mut next_state : ResMut<NextState<L::State>>) {
+    next_state.set(IntroState::Running.into())
 }
 
-fn splash(contexts: EguiContexts, assets: Res<EguiIntroScreen<'static>>) {
-    CentralPanel::default().show(contexts.ctx(), |ui| {
-        if let Some(background) = &assets.background {
-            // https://github.com/emilk/egui/discussions/3383#discussioncomment-7373747
-            EguiImage::new(background.clone()).paint_at(ui, ui.ctx().screen_rect())
+fn remember_previous_screen<L: IntroLabel>(
+    mut previous: ResMut<PreviousEguiIntroScreen<L>>,
+    assets: Res<IntroResource<EguiIntroScreen<'static>, L>>,
+) {
+    previous.0 = Some((**assets).clone());
+}
+
+fn forget_previous_screen<L: IntroLabel>(mut previous: ResMut<PreviousEguiIntroScreen<L>>) {
+    previous.0 = None;
+}
+
+// The layer transform is remembered by egui, so it must not outlive the intro screen
+fn reset_layer_transform(contexts: EguiContexts) {
+    contexts
+        .ctx()
+        .set_transform_layer(LayerId::background(), TSTransform::IDENTITY);
+}
+
+fn splash<L: IntroLabel>(
+    contexts: EguiContexts,
+    assets: Res<IntroResource<EguiIntroScreen<'static>, L>>,
+    previous: Res<PreviousEguiIntroScreen<L>>,
+    progress: Res<TransitionProgress<L>>,
+    hold: Option<Res<SkipHoldProgress<L>>>,
+    intro_progress: Res<IntroProgress<L>>,
+) {
+    let ctx = contexts.ctx();
+    let visibility = progress.visibility();
+    let kind = progress.transition().map(|transition| *transition.kind());
+
+    ctx.set_transform_layer(
+        LayerId::background(),
+        layer_transform(kind, visibility, ctx.screen_rect()),
+    );
+
+    CentralPanel::default().show(ctx, |ui| {
+        let rect = ui.max_rect();
+
+        // A slide that played its exit transition has already disappeared
+        let cross_fading = assets.cross_fade && progress.phase() == &TransitionPhase::Entering;
+        let previous = previous.0.as_ref().filter(|previous| previous.exit_transition.is_none());
+        if let Some(previous) = previous.filter(|_| cross_fading) {
+            ui.allocate_ui_at_rect(rect, |ui| {
+                ui.set_opacity(1.0 - visibility);
+                paint_screen(ui, previous);
+            });
         }
 
-        ui.vertical_centered(|ui| {
-            let total_size = ui.available_size();
-            let image_size = total_size / 5f32;
+        ui.allocate_ui_at_rect(rect, |ui| {
+            if matches!(kind, Some(TransitionKind::Fade)) {
+                ui.set_opacity(visibility);
+            }
+            paint_screen(ui, &assets);
 
-            let spacing = image_size.y * 2f32;
-            ui.add_space(spacing);
+            if let Some(style) = &assets.progress_bar {
+                paint_progress_bar(ui, rect, style, &intro_progress);
+            }
 
-            EguiImage::new(assets.icon.clone())
-                .max_size(image_size)
-                .ui(ui);
+            if let Some(hold) = hold.filter(|hold| hold.is_holding()) {
+                let size = Vec2::new(200.0, 20.0);
+                let center = rect.center_bottom() - Vec2::new(0.0, 32.0 + size.y);
+                ui.put(
+                    Rect::from_center_size(center, size),
+                    ProgressBar::new(hold.fraction()).text(HOLD_TO_SKIP_LABEL),
+                );
+            }
+        });
 
-            ui.add_space(image_size.y / 4f32);
+        if let Some(TransitionKind::FadeThroughColor(color)) = kind {
+            let [r, g, b, a] = color.to_srgba().to_u8_array();
+            let alpha = a as f32 * (1.0 - visibility);
+            ui.painter().rect_filled(
+                ctx.screen_rect(),
+                0.0,
+                Color32::from_rgba_unmultiplied(r, g, b, alpha as u8),
+            );
+        }
+    });
+}
 
-            ui.label(RichText::new(assets.label.as_ref()).heading());
-        })
+// The next slide of a sequence is loaded for a frame, the previous one is kept on screen as it
+// was left by its exit transition so the next one doesn't start from a blank frame
+fn splash_between_slides<L: IntroLabel>(
+    contexts: EguiContexts,
+    previous: Res<PreviousEguiIntroScreen<L>>,
+) {
+    let Some(previous) = previous.0.as_ref() else {
+        return;
+    };
+
+    let ctx = contexts.ctx();
+    CentralPanel::default().show(ctx, |ui| {
+        match previous.exit_transition.map(|transition| *transition.kind()) {
+            None => paint_screen(ui, previous),
+            Some(TransitionKind::FadeThroughColor(color)) => {
+                ui.painter()
+                    .rect_filled(ctx.screen_rect(), 0.0, color32(color));
+            }
+            Some(_) => {}
+        }
     });
 }
 
+// Slides and scales are applied to the whole layer the intro screen is painted on
+fn layer_transform(kind: Option<TransitionKind>, visibility: f32, screen: Rect) -> TSTransform {
+    let hidden = 1.0 - visibility;
+    match kind {
+        Some(TransitionKind::Slide(direction)) => {
+            let offset = match direction {
+                SlideDirection::Left => -screen.width() * hidden * Vec2::X,
+                SlideDirection::Right => screen.width() * hidden * Vec2::X,
+                SlideDirection::Up => -screen.height() * hidden * Vec2::Y,
+                SlideDirection::Down => screen.height() * hidden * Vec2::Y,
+            };
+            TSTransform::from_translation(offset)
+        }
+        // Scaling happens around (0, 0) so the centre is moved back into place
+        Some(TransitionKind::Scale) => {
+            TSTransform::new(screen.center().to_vec2() * hidden, visibility)
+        }
+        _ => TSTransform::IDENTITY,
+    }
+}
+
+fn paint_screen(ui: &mut Ui, assets: &EguiIntroScreen<'static>) {
+    if let Some(background) = &assets.background {
+        // https://github.com/emilk/egui/discussions/3383#discussioncomment-7373747
+        EguiImage::new(background.clone()).paint_at(ui, ui.ctx().screen_rect())
+    }
+
+    ui.vertical_centered(|ui| {
+        let total_size = ui.available_size();
+        let image_size = total_size / 5f32;
+
+        let spacing = image_size.y * 2f32;
+        ui.add_space(spacing);
+
+        EguiImage::new(assets.icon.clone())
+            .max_size(image_size)
+            .ui(ui);
+
+        ui.add_space(image_size.y / 4f32);
+
+        ui.label(RichText::new(assets.label.as_ref()).heading());
+    });
+}
+
+fn paint_progress_bar<L: IntroLabel>(
+    ui: &mut Ui,
+    screen: Rect,
+    style: &ProgressBarStyle,
+    progress: &IntroProgress<L>,
+) {
+    let size = Vec2::new(style.size().x, style.size().y);
+    let offset = style.margin() + size.y / 2.0;
+    let center = match style.position() {
+        ProgressBarPosition::Top => screen.center_top() + Vec2::new(0.0, offset),
+        ProgressBarPosition::Bottom => screen.center_bottom() - Vec2::new(0.0, offset),
+    };
+
+    let height = match progress.fraction() {
+        Some(fraction) => {
+            let bar = Rect::from_center_size(center, size);
+            let mut fill = bar;
+            fill.set_width(bar.width() * fraction);
+
+            let rounding = size.y / 2.0;
+            ui.painter()
+                .rect_filled(bar, rounding, color32(*style.background()));
+            ui.painter()
+                .rect_filled(fill, rounding, color32(*style.foreground()));
+            size.y
+        }
+        None => {
+            let spinner_size = size.y.max(16.0);
+            ui.put(
+                Rect::from_center_size(center, Vec2::splat(spinner_size)),
+                Spinner::new()
+                    .size(spinner_size)
+                    .color(color32(*style.foreground())),
+            );
+            spinner_size
+        }
+    };
+
+    // The message is shown just above the bar
+    if let Some(message) = progress.message() {
+        let message_size = Vec2::new(size.x, 24.0);
+        let message_center = center - Vec2::new(0.0, (height + message_size.y) / 2.0 + 4.0);
+        ui.put(
+            Rect::from_center_size(message_center, message_size),
+            Label::new(RichText::new(message).color(color32(*style.foreground()))),
+        );
+    }
+}
+
+fn color32(color: Color) -> Color32 {
+    let [r, g, b, a] = color.to_srgba().to_u8_array();
+    Color32::from_rgba_unmultiplied(r, g, b, a)
+}
+
 #[derive(Resource)]
-struct FailureMessageResource<T>(T)
+struct FailureMessageResource<T, L>(T, PhantomData<L>)
 where
-    T: AsRef<str> + Send + Sync + 'static;
+    T: AsRef<str> + Send + Sync + 'static,
+    L: IntroLabel;
 
 impl<T> IntroFailureManager for OnFailureShowMessage<T>
 where
     T: Clone + AsRef<str> + Send + Sync + 'static,
 {
-    fn manage_failure<S, D, U>(&self, app: &mut App, schedule: OnEnter<super::IntroState>)
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
     where
         S: States + bevy::state::state::FreelyMutableState,
         D: super::IntroDuration,
         U: ShowIntroScreen,
+        L: IntroLabel,
     {
-        let on_exit_schedule: OnExit<super::IntroState> = OnExit(schedule.0.clone());
-        let message_resource = FailureMessageResource(self.0.clone());
+        let message_resource = FailureMessageResource::<T, L>(self.0.clone(), PhantomData);
 
         app.insert_resource(message_resource)
-            .add_systems(Update, show_message::<T>.run_if(in_state(schedule.0)))
-            .add_systems(on_exit_schedule, clean_up::<FailureMessageResource<T>>);
+            .add_systems(Update, show_message::<T, L>.in_set(schedules.set()))
+            .add_systems(schedules.exit(), clean_up::<FailureMessageResource<T, L>>);
     }
 }
 
-fn show_message<T>(contexts: EguiContexts, res: Res<FailureMessageResource<T>>)
-where
+fn show_message<T, L>(
+    contexts: EguiContexts,
+    res: Res<FailureMessageResource<T, L>>,
+    failure: Res<IntroFailure<L>>,
+) where
     T: AsRef<str> + Send + Sync + 'static,
+    L: IntroLabel,
 {
     TopBottomPanel::bottom("splash_screen_snackbar").show(contexts.ctx(), |ui| {
         ui.vertical_centered_justified(|ui| {
@@ -112,11 +357,78 @@ where
                 RichText::new(res.0.as_ref())
                     .monospace()
                     .color(Color32::LIGHT_RED),
-            )
+            );
+
+            if let Some(reason) = failure.reason() {
+                ui.label(
+                    RichText::new(reason.to_string())
+                        .monospace()
+                        .small()
+                        .color(Color32::LIGHT_RED),
+                );
+            }
         })
     });
 }
 
+impl IntroFailureManager for OnFailureShowDialog {
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
+    where
+        S: States + bevy::state::state::FreelyMutableState,
+        D: super::IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        // The dialog may be shown by an intro using another backend
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin);
+        }
+
+        self.0.configure::<S, D, U, L>(app, &schedules);
+        app.add_systems(Update, show_dialog::<L>.in_set(schedules.set()));
+    }
+}
+
+fn show_dialog<L: IntroLabel>(
+    contexts: EguiContexts,
+    dialog: Res<IntroResource<FailureDialog, L>>,
+    open: Res<FailureDialogOpen<L>>,
+    failure: Res<IntroFailure<L>>,
+    mut choices: EventWriter<FailureDialogChoice<L>>,
+) {
+    if !open.0 {
+        return;
+    }
+
+    Window::new(dialog.title().as_ref())
+        .id(format!(
+            "splash_screen_failure_dialog_{}",
+            std::any::type_name::<L>()
+        )
+        .into())
+        .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
+        .collapsible(false)
+        .resizable(false)
+        .show(contexts.ctx(), |ui| {
+            if let Some(message) = dialog.message() {
+                ui.label(message.as_ref());
+            }
+
+            if let Some(reason) = failure.reason().filter(|_| *dialog.show_reason()) {
+                ui.label(RichText::new(reason.to_string()).small().weak());
+            }
+
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                for button in dialog.buttons() {
+                    if ui.button(button.label()).clicked() {
+                        choices.send(FailureDialogChoice::new(*button));
+                    }
+                }
+            });
+        });
+}
+
 fn clean_up<T: Resource>(commands: Commands) {
   //  commands.remove_resource::<T>()
 }
@@ -0,0 +1,167 @@
+use std::{marker::PhantomData, time::Duration};
+
+use bevy::{ecs::system::SystemParam, prelude::*, state::state::FreelyMutableState};
+
+use super::{
+    label::IntroLabel,
+    reason::{IntroFailure, IntroFailureReason},
+    skip_screen::SkipInput,
+    state::LabeledState,
+    IntroDuration, IntroPreferences, IntroState, ShowIntroScreen,
+};
+
+/// Sent when [IntroPreferences::run_at] is entered and the intro labelled `L` starts loading.
+#[derive(Event, Clone, Debug)]
+pub struct IntroStarted<L = ()>
+where
+    L: IntroLabel,
+{
+    label: PhantomData<L>,
+}
+
+/// Sent when the intro screen labelled `L` is shown, once it has finished loading.
+///
+/// Sent again for every slide of an [super::sequence::IntroSequence].
+#[derive(Event, Clone, Debug)]
+pub struct IntroRunning<L = ()>
+where
+    L: IntroLabel,
+{
+    /// Time since the intro started.
+    pub elapsed: Duration,
+    label: PhantomData<L>,
+}
+
+/// Sent when the player skips the intro screen labelled `L`.
+#[derive(Event, Clone, Debug)]
+pub struct IntroSkipped<L = ()>
+where
+    L: IntroLabel,
+{
+    /// The input that skipped the intro.
+    pub input: SkipInput,
+    /// Time since the intro started.
+    pub elapsed: Duration,
+    label: PhantomData<L>,
+}
+
+/// Sent when the intro screen labelled `L` finishes on its own and the app moves on from
+/// [IntroPreferences::run_at].
+#[derive(Event, Clone, Debug)]
+pub struct IntroFinished<L = ()>
+where
+    L: IntroLabel,
+{
+    /// Total time the intro took.
+    pub elapsed: Duration,
+    label: PhantomData<L>,
+}
+
+/// Sent when the intro screen labelled `L` enters [IntroState::Failure].
+#[derive(Event, Clone, Debug)]
+pub struct IntroFailed<L = ()>
+where
+    L: IntroLabel,
+{
+    /// Time since the intro started.
+    pub elapsed: Duration,
+    /// Why the intro failed, if the reason is known, see [IntroFailure].
+    pub reason: Option<IntroFailureReason>,
+    label: PhantomData<L>,
+}
+
+// When the current intro started, in real time so pausing virtual time doesn't affect it
+#[derive(Resource)]
+struct IntroStartedAt<L: IntroLabel>(Duration, PhantomData<L>);
+
+// Measures the time since the current intro started
+#[derive(SystemParam)]
+pub(crate) struct IntroClock<'w, L: IntroLabel> {
+    started_at: Res<'w, IntroStartedAt<L>>,
+    time: Res<'w, Time<Real>>,
+}
+
+impl<L: IntroLabel> IntroClock<'_, L> {
+    pub(crate) fn elapsed(&self) -> Duration {
+        self.time.elapsed().saturating_sub(self.started_at.0)
+    }
+}
+
+impl<L: IntroLabel> IntroSkipped<L> {
+    pub(crate) const fn new(input: SkipInput, elapsed: Duration) -> Self {
+        Self {
+            input,
+            elapsed,
+            label: PhantomData,
+        }
+    }
+}
+
+impl<S, D, U, L> IntroPreferences<S, D, U, L>
+where
+    S: States + FreelyMutableState,
+    D: IntroDuration,
+    U: ShowIntroScreen,
+    L: IntroLabel,
+{
+    pub(super) fn add_lifecycle_events(&self, app: &mut App) {
+        app.add_event::<IntroStarted<L>>()
+            .add_event::<IntroRunning<L>>()
+            .add_event::<IntroSkipped<L>>()
+            .add_event::<IntroFinished<L>>()
+            .add_event::<IntroFailed<L>>()
+            .insert_resource(IntroStartedAt::<L>(Duration::ZERO, PhantomData))
+            .add_systems(OnEnter(self.run_at.clone()), intro_started::<L>)
+            .add_systems(
+                OnEnter(L::State::from(IntroState::Running)),
+                intro_running::<L>,
+            )
+            .add_systems(
+                OnEnter(L::State::from(IntroState::Failure)),
+                intro_failed::<L>,
+            )
+            .add_systems(OnExit(self.run_at.clone()), intro_finished::<L>);
+    }
+}
+
+fn intro_started<L: IntroLabel>(
+    mut started_at: ResMut<IntroStartedAt<L>>,
+    mut events: EventWriter<IntroStarted<L>>,
+    time: Res<Time<Real>>,
+) {
+    started_at.0 = time.elapsed();
+    events.send(IntroStarted { label: PhantomData });
+}
+
+fn intro_running<L: IntroLabel>(clock: IntroClock<L>, mut events: EventWriter<IntroRunning<L>>) {
+    events.send(IntroRunning {
+        elapsed: clock.elapsed(),
+        label: PhantomData,
+    });
+}
+
+fn intro_failed<L: IntroLabel>(
+    clock: IntroClock<L>,
+    failure: Res<IntroFailure<L>>,
+    mut events: EventWriter<IntroFailed<L>>,
+) {
+    events.send(IntroFailed {
+        elapsed: clock.elapsed(),
+        reason: failure.reason().cloned(),
+        label: PhantomData,
+    });
+}
+
+// Skipped and failed intros have already been reported when they happened
+fn intro_finished<L: IntroLabel>(
+    clock: IntroClock<L>,
+    state: Res<State<L::State>>,
+    mut events: EventWriter<IntroFinished<L>>,
+) {
+    if state.get().unlabeled().is_running() {
+        events.send(IntroFinished {
+            elapsed: clock.elapsed(),
+            label: PhantomData,
+        });
+    }
+}
@@ -0,0 +1,71 @@
+use std::{fmt::Debug, hash::Hash, marker::PhantomData};
+
+use bevy::prelude::*;
+
+use super::{
+    state::{IntroState, LabeledState},
+    tick::DynamicDurationState,
+};
+
+/// Identifies one of several independent intro screens within the same app.
+///
+/// Every [super::IntroScreenPlugin] added with its own label gets its own state, timers and ui,
+/// so e.g. a boot splash and a loading screen between levels don't interfere with each other.
+/// Any marker type can be used as a label, following the [super::state::LabeledIntroState]
+/// and [super::tick::LabeledDynamicDurationState] of its own:
+///
+/// ```rust,ignore
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+/// struct LevelLoadingScreen;
+///
+/// impl IntroLabel for LevelLoadingScreen {
+///     type State = LabeledIntroState<Self>;
+///     type DynamicState = LabeledDynamicDurationState<Self>;
+/// }
+///
+/// let preferences = IntroPreferences::builder()
+///     // ..
+///     .build()
+///     .with_label(LevelLoadingScreen);
+/// ```
+///
+/// Intros without a label use `()`, which follows [IntroState] and [DynamicDurationState].
+pub trait IntroLabel: Clone + Eq + Hash + Debug + Send + Sync + 'static {
+    /// The state of the intro labelled `Self`.
+    type State: LabeledState<IntroState>;
+    /// The state of the [super::GenericDynamicDuration] of the intro labelled `Self`.
+    type DynamicState: LabeledState<DynamicDurationState>;
+}
+
+impl IntroLabel for () {
+    type State = IntroState;
+    type DynamicState = DynamicDurationState;
+}
+
+/// A resource owned by the intro labelled `L`, so every intro keeps its own copy.
+///
+/// The built-in durations and ui backends store their configuration in this wrapper.
+#[derive(Resource, Deref, DerefMut)]
+pub struct IntroResource<T, L = ()>
+where
+    T: Send + Sync + 'static,
+    L: IntroLabel,
+{
+    #[deref]
+    value: T,
+    label: PhantomData<L>,
+}
+
+impl<T, L> IntroResource<T, L>
+where
+    T: Send + Sync + 'static,
+    L: IntroLabel,
+{
+    /// Wraps `value` as the resource of the intro labelled `L`.
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            label: PhantomData,
+        }
+    }
+}
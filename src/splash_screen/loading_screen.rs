@@ -0,0 +1,147 @@
+use std::{fmt, marker::PhantomData, sync::Arc};
+
+use bevy::{prelude::*, state::state::FreelyMutableState};
+
+use super::{label::IntroLabel, IntroDuration, IntroPreferences, ShowIntroScreen};
+
+type TransitionMatcher<S> = dyn Fn(&S, &S) -> bool + Send + Sync;
+
+/// Decides which transitions of `S` show the intro as a loading screen, see
+/// [IntroPreferences::load_on].
+///
+/// ```rust,ignore
+/// // Show the loading screen whenever a level is entered
+/// TransitionRule::new(|_from, to| matches!(to, GameState::Level(_)))
+/// ```
+#[derive(Clone)]
+pub struct TransitionRule<S>
+where
+    S: States,
+{
+    matches: Arc<TransitionMatcher<S>>,
+}
+
+/// The state the loading screen labelled `L` continues to once it has finished.
+///
+/// Only present if [IntroPreferences::load_on] is set, and only holds a state while a loading
+/// screen triggered by a [TransitionRule] is shown.
+#[derive(Resource, Clone, Debug)]
+pub struct LoadingDestination<S, L = ()>
+where
+    S: States,
+    L: IntroLabel,
+{
+    destination: Option<S>,
+    label: PhantomData<L>,
+}
+
+impl<S> TransitionRule<S>
+where
+    S: States,
+{
+    /// Creates a rule matching every transition `from` one state `to` another for which
+    /// `matches` returns `true`.
+    pub fn new(matches: impl Fn(&S, &S) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            matches: Arc::new(matches),
+        }
+    }
+
+    /// Returns `true` if the transition `from` one state `to` another shows the loading screen.
+    pub fn matches(&self, from: &S, to: &S) -> bool {
+        (self.matches)(from, to)
+    }
+}
+
+impl<S> fmt::Debug for TransitionRule<S>
+where
+    S: States,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransitionRule").finish_non_exhaustive()
+    }
+}
+
+impl<S, L> LoadingDestination<S, L>
+where
+    S: States,
+    L: IntroLabel,
+{
+    /// Returns the state the loading screen continues to, if it was triggered by a [TransitionRule].
+    pub fn get(&self) -> Option<&S> {
+        self.destination.as_ref()
+    }
+
+    fn clear(mut destination: ResMut<Self>) {
+        destination.destination = None;
+    }
+}
+
+impl<S, D, U, L> IntroPreferences<S, D, U, L>
+where
+    S: States + FreelyMutableState,
+    D: IntroDuration,
+    U: ShowIntroScreen,
+    L: IntroLabel,
+{
+    // Both redirects run before `StateTransition`, so they see every state switch requested
+    // during the previous frame. They are chained so a switch redirected to the loading screen
+    // is never looked at by `leave_loading_screen` in the same frame
+    pub(super) fn add_loading_screen_subsystem(&self, app: &mut App) {
+        app.insert_resource(LoadingDestination::<S, L> {
+            destination: None,
+            label: PhantomData,
+        })
+        .add_systems(
+            PreUpdate,
+            (Self::enter_loading_screen, Self::leave_loading_screen).chain(),
+        )
+        .add_systems(
+            OnExit(self.run_at.clone()),
+            LoadingDestination::<S, L>::clear,
+        );
+    }
+
+    fn enter_loading_screen(
+        state: Res<State<S>>,
+        mut next_state: ResMut<NextState<S>>,
+        mut destination: ResMut<LoadingDestination<S, L>>,
+        preferences: Res<Self>,
+    ) {
+        let (NextState::Pending(to), Some(rule)) = (&*next_state, &preferences.load_on) else {
+            return;
+        };
+
+        let from = state.get();
+        if from == &preferences.run_at || to == &preferences.run_at || !rule.matches(from, to) {
+            return;
+        }
+
+        destination.destination = Some(to.clone());
+        next_state.set(preferences.run_at.clone());
+    }
+
+    // `transition_to` stands in for the destination, so the durations and failure managers
+    // don't need to know about it. Without a `transition_to` that's `run_at` itself
+    fn leave_loading_screen(
+        state: Res<State<S>>,
+        mut next_state: ResMut<NextState<S>>,
+        destination: Res<LoadingDestination<S, L>>,
+        preferences: Res<Self>,
+    ) {
+        if state.get() != &preferences.run_at {
+            return;
+        }
+
+        let NextState::Pending(to) = &*next_state else {
+            return;
+        };
+
+        if let Some(destination) = destination
+            .get()
+            .filter(|_| to == &preferences.transition_to)
+        {
+            next_state.set(destination.clone());
+        }
+    }
+}
@@ -6,27 +6,41 @@ pub mod egui;
 ///
 pub mod bevy_ui;
 
+pub(super) mod events;
+pub(super) mod label;
+pub(super) mod loading_screen;
+pub(super) mod progress;
+pub(super) mod reason;
+pub(super) mod restart;
+pub(super) mod sequence;
 pub(super) mod skip_screen;
 pub(super) mod state;
 pub(super) mod tick;
+pub(super) mod time;
+pub(super) mod transition;
 pub(super) mod ui;
 
 use crate::prelude::*;
 use bevy::{prelude::*, state::state::FreelyMutableState};
 use getset::Getters;
+use std::time::Duration;
 /// The main plugin for Bevy's introduction screen system.
 ///
 /// This plugin manages the splash screen lifecycle, including displaying the screen,
 /// handling asset loading, and managing failures.
+///
+/// Several plugins can be added to the same app as long as their preferences have different
+/// [IntroLabel]s, see [IntroPreferences::with_label].
 #[bon::builder]
-pub struct IntroScreenPlugin<S, D, U, F>
+pub struct IntroScreenPlugin<S, D, U, F, L = ()>
 where
     S: States + FreelyMutableState,
     D: IntroDuration,
     U: ShowIntroScreen,
     F: IntroFailureManager,
+    L: IntroLabel,
 {
-    preferences: IntroPreferences<S, D, U>,
+    preferences: IntroPreferences<S, D, U, L>,
     failure_manager: F,
 }
 
@@ -39,63 +53,179 @@ where
 /// * `S`: The state type used by the application.
 /// * `D`: The splash screen duration type.
 /// * `U`: The type responsible for showing the splash screen
+/// * `L`: The [IntroLabel] of the intro, `()` unless set using [IntroPreferences::with_label]
 #[derive(Clone, Debug, Resource, Getters)]
-#[bon::builder]
-pub struct IntroPreferences<S, D, U>
+pub struct IntroPreferences<S, D, U, L = ()>
 where
     S: States,
     D: IntroDuration,
     U: ShowIntroScreen,
+    L: IntroLabel,
 {
     #[getset(get = "pub")]
     pub(in crate::splash_screen) run_at: S,
     #[getset(get = "pub")]
     pub(in crate::splash_screen) transition_to: S,
     #[getset(get = "pub")]
-    pub(in crate::splash_screen) skip_on_input: bool,
+    pub(in crate::splash_screen) skip_policy: SkipPolicy,
+    /// How long the intro can not be skipped for after it starts running.
+    #[getset(get = "pub")]
+    pub(in crate::splash_screen) unskippable_for: Option<Duration>,
     #[getset(get = "pub")]
     pub(in crate::splash_screen) duration: D,
     #[getset(get = "pub")]
     pub(in crate::splash_screen) ui: U,
+    /// Shows the intro as a loading screen on every transition of `S` matching the rule.
+    ///
+    /// The transition is redirected to [IntroPreferences::run_at], and once the loading screen
+    /// switches to [IntroPreferences::transition_to] the real destination is entered instead,
+    /// see [LoadingDestination].
+    #[getset(get = "pub")]
+    pub(in crate::splash_screen) load_on: Option<TransitionRule<S>>,
+    /// The clock driving the countdowns of the intro, see [IntroTime].
+    #[getset(get = "pub")]
+    pub(in crate::splash_screen) time_source: IntroTimeSource,
+    /// Whether the countdowns of the intro stop while the primary window is unfocused, so
+    /// the intro doesn't finish unseen.
+    #[getset(get = "pub")]
+    pub(in crate::splash_screen) pause_when_unfocused: bool,
+    /// Tells this intro apart from the other intros in the app.
+    #[getset(get = "pub")]
+    pub(in crate::splash_screen) label: L,
+}
+
+// The builder is only available for unlabelled preferences, so the label doesn't have to be
+// spelled out by apps with a single intro
+#[bon::bon]
+impl<S, D, U> IntroPreferences<S, D, U>
+where
+    S: States,
+    D: IntroDuration,
+    U: ShowIntroScreen,
+{
+    /// Creates new unlabelled `IntroPreferences`.
+    ///
+    /// `transition_to` may only be left out if `load_on` is set, [IntroPreferences::run_at] then
+    /// stands in for it, so the intro is only ever left for the [LoadingDestination].
+    ///
+    /// `skip_policy` defaults to [SkipPolicy::default]. The deprecated `skip_on_input` is only
+    /// kept for existing apps and used if `skip_policy` isn't set: `true` stands for
+    /// [SkipPolicy::default] and `false` for [SkipPolicy::disabled].
+    ///
+    /// # Panics
+    ///
+    /// Panics if neither `transition_to` nor `load_on` is set.
+    #[builder]
+    pub fn new(
+        run_at: S,
+        transition_to: Option<S>,
+        skip_policy: Option<SkipPolicy>,
+        skip_on_input: Option<bool>,
+        unskippable_for: Option<Duration>,
+        duration: D,
+        ui: U,
+        load_on: Option<TransitionRule<S>>,
+        #[builder(default)] time_source: IntroTimeSource,
+        #[builder(default)] pause_when_unfocused: bool,
+    ) -> Self {
+        let transition_to = transition_to
+            .or_else(|| load_on.as_ref().map(|_| run_at.clone()))
+            .expect("`transition_to` has to be set unless the intro is a loading screen (`load_on`)");
+        let skip_policy = skip_policy
+            .or_else(|| skip_on_input.map(SkipPolicy::from_skip_on_input))
+            .unwrap_or_default();
+
+        Self {
+            run_at,
+            transition_to,
+            skip_policy,
+            unskippable_for,
+            duration,
+            ui,
+            load_on,
+            time_source,
+            pause_when_unfocused,
+            label: (),
+        }
+    }
+
+    /// Labels these preferences, so the intro doesn't interfere with the other intros in the app.
+    pub fn with_label<L>(self, label: L) -> IntroPreferences<S, D, U, L>
+    where
+        L: IntroLabel,
+    {
+        IntroPreferences {
+            run_at: self.run_at,
+            transition_to: self.transition_to,
+            skip_policy: self.skip_policy,
+            unskippable_for: self.unskippable_for,
+            duration: self.duration,
+            ui: self.ui,
+            load_on: self.load_on,
+            time_source: self.time_source,
+            pause_when_unfocused: self.pause_when_unfocused,
+            label,
+        }
+    }
 }
 
-impl<S, D, U, F> Plugin for IntroScreenPlugin<S, D, U, F>
+impl<S, D, U, F, L> Plugin for IntroScreenPlugin<S, D, U, F, L>
 where
     S: States + FreelyMutableState,
     D: IntroDuration,
     U: ShowIntroScreen + Clone,
     F: IntroFailureManager + Clone,
+    L: IntroLabel,
 {
     fn build(&self, app: &mut App) {
-        app.init_state::<state::IntroState>()
+        app.init_state::<L::State>()
+            .init_resource::<state::InRunAt<L>>()
             .insert_resource(self.preferences.clone())
-            .add_systems(OnEnter(self.preferences.run_at.clone()), change_state)
+            .add_systems(OnEnter(self.preferences.run_at.clone()), change_state::<L>)
             .add_systems(
                 OnExit(self.preferences.run_at.clone()),
-                splash_finish::<S, D, U>,
+                splash_finish::<L>,
             );
 
+        self.preferences.add_lifecycle_events(app);
+        self.preferences.add_progress(app);
+        self.preferences.add_intro_time(app);
+        self.preferences.add_failure_reason(app);
+        self.preferences.add_transitions(app);
+        self.preferences.add_restart(app);
+
         self.preferences
             .duration
             .configure_duration(app, &self.preferences);
 
         self.preferences.ui.configure_ui(app, &self.preferences);
 
-        if self.preferences.skip_on_input {
+        if !self.preferences.skip_policy.is_disabled() {
             self.preferences.add_skip_screen_subsystem(app);
         }
 
+        if self.preferences.load_on.is_some() {
+            self.preferences.add_loading_screen_subsystem(app);
+        }
+
+        self.preferences.add_failure_schedules(app);
         self.failure_manager
-            .manage_failure::<S, D, U>(app, OnEnter(IntroState::Failure))
+            .manage_failure_in::<S, D, U, L>(app, IntroFailureSchedules::new())
     }
 }
 
-fn change_state(mut next_state: ResMut<NextState<state::IntroState>>) {
-    next_state.set(state::IntroState::Loading)
+fn change_state<L: IntroLabel>(
+    mut next_state: ResMut<NextState<L::State>>,
+    mut in_run_at: ResMut<state::InRunAt<L>>,
+) {
+    in_run_at.0 = true;
+    next_state.set(IntroState::Loading.into())
 }
 
-fn splash_finish<S: States, D: IntroDuration, U: ShowIntroScreen>(
-    mut next_state: ResMut<NextState<state::IntroState>>,
+fn splash_finish<L: IntroLabel>(
+    mut next_state: ResMut<NextState<L::State>>,
+    mut in_run_at: ResMut<state::InRunAt<L>>,
 ) {
-    next_state.set(state::IntroState::Idle);
+    in_run_at.0 = false;
+    next_state.set(IntroState::Idle.into());
 }
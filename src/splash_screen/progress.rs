@@ -0,0 +1,132 @@
+use std::{borrow::Cow, marker::PhantomData};
+
+use bevy::{prelude::*, state::state::FreelyMutableState};
+use getset::Getters;
+
+use super::{label::IntroLabel, IntroDuration, IntroPreferences, ShowIntroScreen};
+
+/// How far along the work behind the intro labelled `L` is, shown by the progress bar of the
+/// built-in intro screens.
+///
+/// The progress is set by durations that know it, like [super::tick::AssetLoadingDuration], or
+/// by the game itself. Until it is set, the progress is unknown and a spinner is shown instead.
+/// It is reset every time the intro finishes.
+///
+/// A message describing the current work, like "Loading audio", can be shown next to the bar.
+#[derive(Resource, Clone, Debug)]
+pub struct IntroProgress<L = ()>
+where
+    L: IntroLabel,
+{
+    fraction: Option<f32>,
+    message: Option<Cow<'static, str>>,
+    label: PhantomData<L>,
+}
+
+/// Where the progress bar is placed on the intro screen.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ProgressBarPosition {
+    /// At the top of the screen.
+    Top,
+    /// At the bottom of the screen.
+    #[default]
+    Bottom,
+}
+
+/// The look of the progress bar shown by the built-in intro screens, see [IntroProgress].
+#[derive(Clone, Debug, PartialEq, Getters)]
+#[bon::builder]
+pub struct ProgressBarStyle {
+    /// The edge of the screen the bar is placed at.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    position: ProgressBarPosition,
+    /// The distance between the bar and [ProgressBarStyle::position], in logical pixels.
+    #[builder(default = 64.0)]
+    #[getset(get = "pub")]
+    margin: f32,
+    /// The width and height of the bar, in logical pixels.
+    #[builder(default = Vec2::new(300.0, 8.0))]
+    #[getset(get = "pub")]
+    size: Vec2,
+    /// The colour of the filled part of the bar.
+    #[builder(default = Color::WHITE)]
+    #[getset(get = "pub")]
+    foreground: Color,
+    /// The colour of the empty part of the bar.
+    #[builder(default = Color::srgba(1.0, 1.0, 1.0, 0.25))]
+    #[getset(get = "pub")]
+    background: Color,
+}
+
+impl<L> Default for IntroProgress<L>
+where
+    L: IntroLabel,
+{
+    fn default() -> Self {
+        Self {
+            fraction: None,
+            message: None,
+            label: PhantomData,
+        }
+    }
+}
+
+impl<L> IntroProgress<L>
+where
+    L: IntroLabel,
+{
+    /// Returns how far along the work is, from `0.0` to `1.0`, or `None` if it is unknown.
+    pub fn fraction(&self) -> Option<f32> {
+        self.fraction
+    }
+
+    /// Sets how far along the work is, clamped to `0.0..=1.0`.
+    pub fn set(&mut self, fraction: f32) {
+        self.fraction = Some(fraction.clamp(0.0, 1.0));
+    }
+
+    /// Marks the progress as unknown, showing a spinner instead of a bar.
+    pub fn set_indeterminate(&mut self) {
+        self.fraction = None;
+    }
+
+    /// Returns the message describing the current work, if any.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Sets the message describing the current work, shown next to the progress bar.
+    pub fn set_message(&mut self, message: impl Into<Cow<'static, str>>) {
+        self.message = Some(message.into());
+    }
+
+    /// Removes the message describing the current work.
+    pub fn clear_message(&mut self) {
+        self.message = None;
+    }
+}
+
+impl Default for ProgressBarStyle {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl<S, D, U, L> IntroPreferences<S, D, U, L>
+where
+    S: States + FreelyMutableState,
+    D: IntroDuration,
+    U: ShowIntroScreen,
+    L: IntroLabel,
+{
+    pub(super) fn add_progress(&self, app: &mut App) {
+        app.init_resource::<IntroProgress<L>>()
+            .add_systems(OnExit(self.run_at.clone()), reset_progress::<L>);
+    }
+}
+
+fn reset_progress<L: IntroLabel>(mut progress: ResMut<IntroProgress<L>>) {
+    progress.set_indeterminate();
+    progress.clear_message();
+}
@@ -0,0 +1,168 @@
+use std::{borrow::Cow, fmt, marker::PhantomData, time::Duration};
+
+use bevy::{prelude::*, state::state::FreelyMutableState};
+
+use super::{
+    label::IntroLabel, state::LabeledState, IntroDuration, IntroPreferences, IntroState,
+    ShowIntroScreen,
+};
+
+/// Why an intro entered [IntroState::Failure].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntroFailureReason {
+    /// An asset of the intro failed to load.
+    AssetLoad {
+        /// The path of the asset, if it was loaded from one.
+        path: Option<String>,
+        /// What went wrong while loading the asset.
+        error: String,
+    },
+    /// A dynamic duration didn't complete within its maximum duration.
+    Timeout(Duration),
+    /// A background task of a [super::tick::TaskDuration] failed.
+    Task {
+        /// The name of the task.
+        name: String,
+        /// The error returned by the task.
+        error: String,
+    },
+    /// A failure reported by the game using [IntroFailure::fail].
+    Custom(Cow<'static, str>),
+}
+
+impl fmt::Display for IntroFailureReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AssetLoad {
+                path: Some(path),
+                error,
+            } => write!(f, "failed to load '{path}': {error}"),
+            Self::AssetLoad { path: None, error } => write!(f, "failed to load an asset: {error}"),
+            Self::Timeout(duration) => write!(f, "timed out after {duration:?}"),
+            Self::Task { name, error } => write!(f, "task '{name}' failed: {error}"),
+            Self::Custom(message) => f.write_str(message),
+        }
+    }
+}
+
+/// Why the intro labelled `L` failed, readable by [super::IntroFailureManager]s once
+/// [IntroState::Failure] is entered.
+///
+/// Only the first reason reported during a load of the intro is kept, as later failures are
+/// usually caused by the first one.
+#[derive(Resource, Clone, Debug)]
+pub struct IntroFailure<L = ()>
+where
+    L: IntroLabel,
+{
+    reason: Option<IntroFailureReason>,
+    requested: bool,
+    label: PhantomData<L>,
+}
+
+impl<L> Default for IntroFailure<L>
+where
+    L: IntroLabel,
+{
+    fn default() -> Self {
+        Self {
+            reason: None,
+            requested: false,
+            label: PhantomData,
+        }
+    }
+}
+
+impl<L> IntroFailure<L>
+where
+    L: IntroLabel,
+{
+    /// Returns why the intro failed, if the reason is known.
+    pub fn reason(&self) -> Option<&IntroFailureReason> {
+        self.reason.as_ref()
+    }
+
+    /// Fails the intro because of `reason`, entering [IntroState::Failure] if the intro is
+    /// loading or running.
+    pub fn fail(&mut self, reason: impl Into<IntroFailureReason>) {
+        self.report(reason.into());
+        self.requested = true;
+    }
+
+    pub(crate) fn report(&mut self, reason: IntroFailureReason) {
+        self.reason.get_or_insert(reason);
+    }
+}
+
+/// Send this event to dismiss the failure of the intro labelled `L`, so a [super::Then] moves
+/// on to its next manager.
+#[derive(Event, Clone, Debug)]
+pub struct DismissIntroFailure<L = ()>
+where
+    L: IntroLabel,
+{
+    label: PhantomData<L>,
+}
+
+impl<L> Default for DismissIntroFailure<L>
+where
+    L: IntroLabel,
+{
+    fn default() -> Self {
+        Self { label: PhantomData }
+    }
+}
+
+impl From<&'static str> for IntroFailureReason {
+    fn from(message: &'static str) -> Self {
+        Self::Custom(Cow::Borrowed(message))
+    }
+}
+
+impl From<String> for IntroFailureReason {
+    fn from(message: String) -> Self {
+        Self::Custom(Cow::Owned(message))
+    }
+}
+
+impl<S, D, U, L> IntroPreferences<S, D, U, L>
+where
+    S: States + FreelyMutableState,
+    D: IntroDuration,
+    U: ShowIntroScreen,
+    L: IntroLabel,
+{
+    pub(super) fn add_failure_reason(&self, app: &mut App) {
+        app.init_resource::<IntroFailure<L>>()
+            .add_event::<DismissIntroFailure<L>>()
+            // Every load starts without a reason, including retries and slides of a sequence
+            .add_systems(
+                OnEnter(L::State::from(IntroState::Loading)),
+                clear_failure::<L>,
+            )
+            .add_systems(Update, apply_requested_failure::<L>);
+    }
+}
+
+fn clear_failure<L: IntroLabel>(mut failure: ResMut<IntroFailure<L>>) {
+    failure.reason = None;
+    failure.requested = false;
+}
+
+fn apply_requested_failure<L: IntroLabel>(
+    mut failure: ResMut<IntroFailure<L>>,
+    state: Res<State<L::State>>,
+    mut next_state: ResMut<NextState<L::State>>,
+) {
+    if !failure.requested {
+        return;
+    }
+
+    failure.requested = false;
+    if matches!(
+        state.get().unlabeled(),
+        IntroState::Loading | IntroState::Running
+    ) {
+        next_state.set(IntroState::Failure.into());
+    }
+}
@@ -0,0 +1,102 @@
+use std::marker::PhantomData;
+
+use bevy::{
+    ecs::{schedule::ScheduleLabel, system::SystemParam},
+    prelude::*,
+    state::state::FreelyMutableState,
+};
+
+use super::{
+    label::IntroLabel, state::LabeledState, IntroDuration, IntroPreferences, IntroState,
+    ShowIntroScreen,
+};
+
+/// The schedule run every time the intro labelled `L` starts loading, both when
+/// [IntroPreferences::run_at] is entered and whenever the intro is loaded again, e.g. by
+/// [super::OnFailureRetry].
+///
+/// The durations reset their timers, tasks and tracked assets in here, so a restarted intro
+/// doesn't pick up where the failed attempt left off. Custom [IntroDuration]s should do the same.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnIntroStart<L = ()>(PhantomData<L>)
+where
+    L: IntroLabel;
+
+// Whether the intro is on its way back to loading through `Idle`
+#[derive(Resource)]
+struct Restarting<L: IntroLabel>(bool, PhantomData<L>);
+
+// Loads the intro labelled `L` again, shared by the failure managers that retry it. While the
+// intro is still running, this should happen before `Update` so the durations can't fail it again
+#[derive(SystemParam)]
+pub(crate) struct IntroRestart<'w, 's, L>
+where
+    L: IntroLabel,
+{
+    commands: Commands<'w, 's>,
+    state: Res<'w, State<<L as IntroLabel>::State>>,
+    next_state: ResMut<'w, NextState<<L as IntroLabel>::State>>,
+    restarting: ResMut<'w, Restarting<L>>,
+}
+
+impl<L> Default for OnIntroStart<L>
+where
+    L: IntroLabel,
+{
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<L> IntroRestart<'_, '_, L>
+where
+    L: IntroLabel,
+{
+    /// Resets the durations and loads the intro again.
+    pub(crate) fn restart(&mut self) {
+        self.commands.add(run_intro_start::<L>);
+
+        // Loading has to be left before it can be entered again
+        match self.state.get().unlabeled() {
+            IntroState::Failure | IntroState::Idle => {
+                self.next_state.set(IntroState::Loading.into())
+            }
+            _ => {
+                self.restarting.0 = true;
+                self.next_state.set(IntroState::Idle.into());
+            }
+        }
+    }
+}
+
+impl<S, D, U, L> IntroPreferences<S, D, U, L>
+where
+    S: States + FreelyMutableState,
+    D: IntroDuration,
+    U: ShowIntroScreen,
+    L: IntroLabel,
+{
+    pub(super) fn add_restart(&self, app: &mut App) {
+        app.init_schedule(OnIntroStart::<L>::default())
+            .insert_resource(Restarting::<L>(false, PhantomData))
+            .add_systems(OnEnter(self.run_at.clone()), run_intro_start::<L>)
+            .add_systems(
+                OnEnter(L::State::from(IntroState::Idle)),
+                finish_restart::<L>,
+            );
+    }
+}
+
+fn run_intro_start<L: IntroLabel>(world: &mut World) {
+    world.run_schedule(OnIntroStart::<L>::default());
+}
+
+fn finish_restart<L: IntroLabel>(
+    mut restarting: ResMut<Restarting<L>>,
+    mut next_state: ResMut<NextState<L::State>>,
+) {
+    if restarting.0 {
+        restarting.0 = false;
+        next_state.set(IntroState::Loading.into());
+    }
+}
@@ -0,0 +1,199 @@
+use std::{marker::PhantomData, time::Duration};
+
+use bevy::{prelude::*, state::state::FreelyMutableState};
+use getset::Getters;
+
+use crate::splash_screen::{
+    restart::OnIntroStart, time::IntroTime, IntroDuration, IntroLabel, IntroPreferences, IntroResource,
+    IntroState, ShowIntroScreen,
+};
+
+/// A single slide of an [IntroSequence], e.g. a publisher logo or a "Made with Bevy" card.
+#[derive(Clone, Debug, Getters)]
+#[bon::builder]
+pub struct IntroSlide<T>
+where
+    T: ShowIntroScreen,
+{
+    /// The content shown while this slide is active.
+    #[getset(get = "pub")]
+    ui: T,
+    /// How long the slide is shown for.
+    #[getset(get = "pub")]
+    duration: Duration,
+    /// Whether the intro can be skipped while this slide is shown.
+    #[builder(default = true)]
+    #[getset(get = "pub")]
+    skippable: bool,
+    /// How long the slide can not be skipped for after it starts, overriding
+    /// [IntroPreferences::unskippable_for].
+    #[getset(get = "pub")]
+    unskippable_for: Option<Duration>,
+}
+
+/// An ordered list of [IntroSlide]s shown one after another within a single intro.
+///
+/// The sequence acts as both the [ShowIntroScreen] and the [IntroDuration] of the intro, so it
+/// should be passed to [IntroPreferences::builder] for both `ui` and `duration`.
+///
+/// Every slide goes through [IntroState::Loading] and [IntroState::Running] on its
+/// own, with its `ui` inserted as an [IntroResource] when it starts loading, so backends reading
+/// their configuration from the world (like `EguiIntroScreen`) pick up the content of the active slide.
+/// The rest of the slide's configuration is applied using [ShowIntroScreen::configure_slide].
+/// [IntroPreferences::transition_to] is only set once the last slide has finished.
+#[derive(Clone, Debug, Resource)]
+pub struct IntroSequence<T>
+where
+    T: ShowIntroScreen + Resource + Clone,
+{
+    slides: Vec<IntroSlide<T>>,
+}
+
+/// Tracks the slide of the [IntroSequence] of the intro labelled `L` that is currently shown.
+#[derive(Clone, Debug, Resource, Getters)]
+pub struct CurrentIntroSlide<L = ()>
+where
+    L: IntroLabel,
+{
+    /// Index of the active slide within the sequence.
+    #[getset(get = "pub")]
+    index: usize,
+    /// Whether the active slide can be skipped.
+    #[getset(get = "pub")]
+    skippable: bool,
+    /// How long the active slide can not be skipped for after it starts.
+    #[getset(get = "pub")]
+    unskippable_for: Option<Duration>,
+    timer: Timer,
+    label: PhantomData<L>,
+}
+
+impl<T> IntroSequence<T>
+where
+    T: ShowIntroScreen + Resource + Clone,
+{
+    /// Creates a new `IntroSequence` from the given slides.
+    ///
+    /// # Panics
+    /// Panics if `slides` is empty.
+    pub fn new(slides: impl IntoIterator<Item = IntroSlide<T>>) -> Self {
+        let slides: Vec<_> = slides.into_iter().collect();
+        assert!(!slides.is_empty(), "an IntroSequence needs at least one slide");
+        Self { slides }
+    }
+
+    /// Returns the slides of this sequence in the order they are shown.
+    pub fn slides(&self) -> &[IntroSlide<T>] {
+        &self.slides
+    }
+}
+
+impl<L> CurrentIntroSlide<L>
+where
+    L: IntroLabel,
+{
+    fn new<T>(index: usize, slide: &IntroSlide<T>) -> Self
+    where
+        T: ShowIntroScreen,
+    {
+        Self {
+            index,
+            skippable: slide.skippable,
+            unskippable_for: slide.unskippable_for,
+            timer: Timer::new(slide.duration, TimerMode::Once),
+            label: PhantomData,
+        }
+    }
+}
+
+impl<T> ShowIntroScreen for IntroSequence<T>
+where
+    T: ShowIntroScreen + Resource + Clone,
+{
+    fn configure_ui<S, D, U, L>(&self, app: &mut App, preferences: &IntroPreferences<S, D, U, L>)
+    where
+        S: States,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        // All slides share the same type, so the systems only need to be registered once and
+        // the configuration of the other slides is applied by `show_current_slide`
+        self.slides[0].ui.configure_ui(app, preferences);
+
+        app.insert_resource(IntroResource::<_, L>::new(self.clone()))
+            .add_systems(OnEnter(L::State::from(IntroState::Loading)), show_current_slide::<T, L>);
+    }
+}
+
+impl<T> IntroDuration for IntroSequence<T>
+where
+    T: ShowIntroScreen + Resource + Clone,
+{
+    fn configure_duration<S, D, U, L>(
+        &self,
+        app: &mut App,
+        _preferences: &IntroPreferences<S, D, U, L>,
+    ) where
+        S: States + FreelyMutableState,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        app.insert_resource(IntroResource::<_, L>::new(self.clone()))
+            .insert_resource(CurrentIntroSlide::<L>::new(0, &self.slides[0]))
+            .add_systems(Update, Self::only_run_if_labeled::<L, _>(advance_slide::<S, D, U, L, T>))
+            .add_systems(OnIntroStart::<L>::default(), rewind_sequence::<T, L>);
+    }
+}
+
+fn show_current_slide<T, L>(
+    mut commands: Commands,
+    sequence: Res<IntroResource<IntroSequence<T>, L>>,
+    current: Res<CurrentIntroSlide<L>>,
+) where
+    T: ShowIntroScreen + Resource + Clone,
+    L: IntroLabel,
+{
+    let ui = sequence.slides[current.index].ui.clone();
+    ui.configure_slide::<L>(&mut commands);
+    commands.insert_resource(IntroResource::<_, L>::new(ui));
+}
+
+fn rewind_sequence<T, L>(
+    mut current: ResMut<CurrentIntroSlide<L>>,
+    sequence: Res<IntroResource<IntroSequence<T>, L>>,
+) where
+    T: ShowIntroScreen + Resource + Clone,
+    L: IntroLabel,
+{
+    *current = CurrentIntroSlide::new(0, &sequence.slides[0]);
+}
+
+fn advance_slide<S, D, U, L, T>(
+    mut next_intro_state: ResMut<NextState<L::State>>,
+    mut next_state: ResMut<NextState<S>>,
+    mut current: ResMut<CurrentIntroSlide<L>>,
+    sequence: Res<IntroResource<IntroSequence<T>, L>>,
+    preferences: Res<IntroPreferences<S, D, U, L>>,
+    time: Res<IntroTime<L>>,
+) where
+    S: States + FreelyMutableState,
+    D: IntroDuration,
+    U: ShowIntroScreen,
+    L: IntroLabel,
+    T: ShowIntroScreen + Resource + Clone,
+{
+    if !current.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let index = current.index + 1;
+    match sequence.slides.get(index) {
+        Some(slide) => {
+            *current = CurrentIntroSlide::new(index, slide);
+            next_intro_state.set(IntroState::Loading.into());
+        }
+        None => next_state.set(preferences.transition_to.clone()),
+    }
+}
@@ -1,23 +1,330 @@
-use super::{ShowIntroScreen, IntroDuration, IntroPreferences};
-use bevy::{prelude::*, state::state::FreelyMutableState};
+use super::{events::{IntroClock, IntroSkipped}, label::IntroLabel, sequence::CurrentIntroSlide, time::IntroTime, ShowIntroScreen, IntroDuration, IntroPreferences, IntroState};
+use bevy::{ecs::system::SystemParam, input::touch::Touches, prelude::*, state::state::FreelyMutableState};
+use getset::Getters;
+use std::{marker::PhantomData, time::Duration};
 
-impl<S, D, U> IntroPreferences<S, D, U>
+// Shown next to the hold progress by the built-in intro screens
+#[cfg(any(feature = "egui", feature = "bevy_ui"))]
+pub(crate) const HOLD_TO_SKIP_LABEL: &str = "Hold to skip";
+
+/// Describes which inputs skip the intro screen.
+///
+/// The [Default] policy, like the builder, skips on `Escape`, `Space` and `Enter`, use
+/// [SkipPolicy::disabled] for intros that can not be skipped at all.
+#[derive(Clone, Debug, PartialEq, Eq, Getters)]
+#[bon::builder]
+pub struct SkipPolicy {
+    /// Keyboard keys that skip the intro, `Escape`, `Space` and `Enter` unless set.
+    #[builder(default = vec![KeyCode::Escape, KeyCode::Space, KeyCode::Enter])]
+    #[getset(get = "pub")]
+    keys: Vec<KeyCode>,
+    /// Mouse buttons that skip the intro.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    mouse_buttons: Vec<MouseButton>,
+    /// Gamepad buttons that skip the intro, on any connected gamepad.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    gamepad_buttons: Vec<GamepadButtonType>,
+    /// Whether tapping the screen skips the intro.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    touch: bool,
+    /// Whether any keyboard key skips the intro, in addition to [SkipPolicy::keys].
+    #[builder(default)]
+    #[getset(get = "pub")]
+    any_key: bool,
+    /// How long one of the inputs has to be held down to skip the intro.
+    ///
+    /// If `None`, the intro is skipped as soon as one of the inputs is pressed.
+    #[getset(get = "pub")]
+    hold: Option<Duration>,
+}
+
+/// Progress of holding down a skip input of the intro labelled `L`, only present if
+/// [SkipPolicy::hold] is set.
+#[derive(Resource, Clone, Debug)]
+pub struct SkipHoldProgress<L = ()>
+where
+    L: IntroLabel,
+{
+    held: Duration,
+    required: Duration,
+    label: PhantomData<L>,
+}
+
+/// Keeps the intro labelled `L` from being skipped for a while after it starts running.
+///
+/// The window is reopened every time the intro screen (or a slide of an
+/// [super::sequence::IntroSequence]) starts running, lasting
+/// [IntroPreferences::unskippable_for] or the slide's own setting.
+#[derive(Resource, Clone, Debug)]
+pub struct UnskippableWindow<L = ()>
+where
+    L: IntroLabel,
+{
+    timer: Timer,
+    label: PhantomData<L>,
+}
+
+/// The input that skipped the intro screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SkipInput {
+    /// A keyboard key.
+    Key(KeyCode),
+    /// A mouse button.
+    MouseButton(MouseButton),
+    /// A button on a gamepad.
+    GamepadButton(GamepadButton),
+    /// A tap on a touch screen.
+    Touch,
+}
+
+// The input resources a `SkipPolicy` is checked against, missing ones are treated as idle
+// so apps without every input source (e.g. headless or touch-only builds) can still skip
+#[derive(SystemParam)]
+struct SkipInputs<'w> {
+    keys: Option<Res<'w, ButtonInput<KeyCode>>>,
+    mouse_buttons: Option<Res<'w, ButtonInput<MouseButton>>>,
+    gamepad_buttons: Option<Res<'w, ButtonInput<GamepadButton>>>,
+    touches: Option<Res<'w, Touches>>,
+}
+
+impl Default for SkipPolicy {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl SkipPolicy {
+    // The policy standing in for the deprecated `skip_on_input` of the preferences builder
+    pub(crate) fn from_skip_on_input(skip_on_input: bool) -> Self {
+        match skip_on_input {
+            true => Self::default(),
+            false => Self::disabled(),
+        }
+    }
+
+    /// Creates a policy under which the intro can not be skipped.
+    pub const fn disabled() -> Self {
+        Self {
+            keys: Vec::new(),
+            mouse_buttons: Vec::new(),
+            gamepad_buttons: Vec::new(),
+            touch: false,
+            any_key: false,
+            hold: None,
+        }
+    }
+
+    /// Creates a policy that skips the intro on any keyboard key.
+    pub const fn on_any_key() -> Self {
+        Self {
+            keys: Vec::new(),
+            mouse_buttons: Vec::new(),
+            gamepad_buttons: Vec::new(),
+            touch: false,
+            any_key: true,
+            hold: None,
+        }
+    }
+
+    /// Returns `true` if no input skips the intro.
+    pub fn is_disabled(&self) -> bool {
+        self.keys.is_empty()
+            && self.mouse_buttons.is_empty()
+            && self.gamepad_buttons.is_empty()
+            && !self.touch
+            && !self.any_key
+    }
+
+    fn just_pressed(&self, inputs: &SkipInputs) -> Option<SkipInput> {
+        self.find(
+            inputs.keys.iter().flat_map(|keys| keys.get_just_pressed()),
+            inputs.mouse_buttons.iter().flat_map(|buttons| buttons.get_just_pressed()),
+            inputs.gamepad_buttons.iter().flat_map(|buttons| buttons.get_just_pressed()),
+            inputs.touches.as_ref().is_some_and(|touches| touches.any_just_pressed()),
+        )
+    }
+
+    fn pressed(&self, inputs: &SkipInputs) -> Option<SkipInput> {
+        self.find(
+            inputs.keys.iter().flat_map(|keys| keys.get_pressed()),
+            inputs.mouse_buttons.iter().flat_map(|buttons| buttons.get_pressed()),
+            inputs.gamepad_buttons.iter().flat_map(|buttons| buttons.get_pressed()),
+            inputs.touches.as_ref().is_some_and(|touches| touches.iter().next().is_some()),
+        )
+    }
+
+    // Returns the first of the given inputs that skips the intro under this policy
+    fn find<'a>(
+        &self,
+        mut keys: impl Iterator<Item = &'a KeyCode>,
+        mut mouse_buttons: impl Iterator<Item = &'a MouseButton>,
+        mut gamepad_buttons: impl Iterator<Item = &'a GamepadButton>,
+        touched: bool,
+    ) -> Option<SkipInput> {
+        let key = keys
+            .find(|key| self.any_key || self.keys.contains(key))
+            .map(|key| SkipInput::Key(*key));
+
+        let mouse_button = mouse_buttons
+            .find(|button| self.mouse_buttons.contains(button))
+            .map(|button| SkipInput::MouseButton(*button));
+
+        // Gamepads are matched by button type so that every connected gamepad can skip
+        let gamepad_button = gamepad_buttons
+            .find(|button| self.gamepad_buttons.contains(&button.button_type))
+            .map(|button| SkipInput::GamepadButton(*button));
+
+        let touch = (self.touch && touched).then_some(SkipInput::Touch);
+
+        key.or(mouse_button).or(gamepad_button).or(touch)
+    }
+}
+
+impl<L> Default for UnskippableWindow<L>
+where
+    L: IntroLabel,
+{
+    fn default() -> Self {
+        Self {
+            timer: Timer::default(),
+            label: PhantomData,
+        }
+    }
+}
+
+impl<L> UnskippableWindow<L>
+where
+    L: IntroLabel,
+{
+    /// Returns `true` once the intro may be skipped.
+    pub fn is_closed(&self) -> bool {
+        self.timer.finished()
+    }
+
+    /// Returns how long the intro remains unskippable.
+    pub fn remaining(&self) -> Duration {
+        self.timer.remaining()
+    }
+
+    fn open<S, D, U>(
+        mut window: ResMut<Self>,
+        preferences: Res<IntroPreferences<S, D, U, L>>,
+        current_slide: Option<Res<CurrentIntroSlide<L>>>,
+    ) where
+        S: States,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+    {
+        let duration = current_slide
+            .and_then(|slide| *slide.unskippable_for())
+            .or(preferences.unskippable_for)
+            .unwrap_or_default();
+
+        window.timer = Timer::new(duration, TimerMode::Once);
+    }
+
+    fn tick(mut window: ResMut<Self>, time: Res<IntroTime<L>>) {
+        window.timer.tick(time.delta());
+    }
+}
+
+impl<L> SkipHoldProgress<L>
+where
+    L: IntroLabel,
+{
+    fn new(required: Duration) -> Self {
+        Self {
+            held: Duration::ZERO,
+            required,
+            label: PhantomData,
+        }
+    }
+
+    /// Returns how far the hold has progressed, from `0.0` to `1.0`.
+    pub fn fraction(&self) -> f32 {
+        if self.required.is_zero() {
+            return 1.0;
+        }
+
+        (self.held.as_secs_f32() / self.required.as_secs_f32()).min(1.0)
+    }
+
+    /// Returns `true` while a skip input is being held down.
+    pub fn is_holding(&self) -> bool {
+        !self.held.is_zero()
+    }
+
+    // Returns the held input once it has been held for long enough
+    fn update(&mut self, input: Option<SkipInput>, delta: Duration) -> Option<SkipInput> {
+        self.held = match input {
+            Some(_) => self.held + delta,
+            None => Duration::ZERO,
+        };
+
+        input.filter(|_| self.held >= self.required)
+    }
+
+    fn reset(mut progress: ResMut<Self>) {
+        progress.held = Duration::ZERO;
+    }
+}
+
+impl<S, D, U, L> IntroPreferences<S, D, U, L>
 where
     S: States + FreelyMutableState + Clone,
     D: IntroDuration,
     U: ShowIntroScreen,
+    L: IntroLabel,
 {
     pub(super) fn add_skip_screen_subsystem(&self, app: &mut App) {
-        app.add_systems(Update, Self::skip_screen.run_if(super::state::is_running));
+        app.init_resource::<UnskippableWindow<L>>()
+            .add_systems(OnEnter(L::State::from(IntroState::Running)), UnskippableWindow::<L>::open::<S, D, U>)
+            .add_systems(
+                Update,
+                (
+                    UnskippableWindow::<L>::tick.run_if(super::state::is_running_labeled::<L>),
+                    Self::skip_screen.run_if(super::state::can_skip_labeled::<L>),
+                )
+                    .chain(),
+            )
+            .add_systems(OnEnter(L::State::from(IntroState::Skipped)), Self::finish_skipped);
+
+        if let Some(required) = self.skip_policy.hold {
+            app.insert_resource(SkipHoldProgress::<L>::new(required))
+                .add_systems(OnExit(L::State::from(IntroState::Running)), SkipHoldProgress::<L>::reset);
+        }
     }
 
+    // Skipping goes through `IntroState::Skipped` so the exit transition can play and the
+    // durations and ui can clean up before the state switch, the same way they would when the
+    // intro finishes on its own
     fn skip_screen(
-        input: Res<ButtonInput<KeyCode>>,
-        mut state: ResMut<NextState<S>>,
-        settings: Res<IntroPreferences<S, D, U>>,
+        inputs: SkipInputs,
+        mut state: ResMut<NextState<L::State>>,
+        mut skipped: EventWriter<IntroSkipped<L>>,
+        settings: Res<Self>,
+        hold: Option<ResMut<SkipHoldProgress<L>>>,
+        clock: IntroClock<L>,
+        time: Res<IntroTime<L>>,
     ) {
-        if input.any_just_pressed([KeyCode::Escape, KeyCode::Space, KeyCode::Enter]) {
-            state.set(settings.transition_to.clone());
+        let input = match hold {
+            Some(mut hold) => hold.update(settings.skip_policy.pressed(&inputs), time.delta()),
+            None => settings.skip_policy.just_pressed(&inputs),
+        };
+
+        if let Some(input) = input {
+            state.set(IntroState::Skipped.into());
+            skipped.send(IntroSkipped::new(input, clock.elapsed()));
         }
     }
+
+    fn finish_skipped(
+        mut state: ResMut<NextState<S>>,
+        settings: Res<Self>,
+    ) {
+        state.set(settings.transition_to.clone());
+    }
 }
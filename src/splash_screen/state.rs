@@ -1,7 +1,18 @@
-use bevy::prelude::*;
+use std::marker::PhantomData;
+
+use bevy::{prelude::*, state::state::FreelyMutableState};
+
+use super::{
+    label::IntroLabel,
+    sequence::CurrentIntroSlide,
+    skip_screen::UnskippableWindow,
+    transition::{TransitionPhase, TransitionProgress},
+};
 
 #[derive(PartialOrd, PartialEq, Copy, Clone, Resource, Default, States, Debug, Hash, Eq)]
 /// Represents the different states of a splash screen.
+///
+/// Intros with a label follow their own state instead, see [IntroLabel::State].
 pub enum IntroState {
     /// **For Internal Use Only**
     /// Idle state, before the splash screen starts.
@@ -13,6 +24,9 @@ pub enum IntroState {
     Running,
     /// Error state indicating a failure during splash screen loading.
     Failure,
+    /// The player skipped the splash screen, it is being torn down before switching to
+    /// [super::IntroPreferences::transition_to].
+    Skipped,
 }
 
 impl IntroState {
@@ -30,6 +44,80 @@ impl IntroState {
     pub const fn is_failure(&self) -> bool {
         matches!(&self, IntroState::Failure)
     }
+
+    /// Returns `true` if the splash screen has been skipped.
+    pub const fn is_skipped(&self) -> bool {
+        matches!(&self, IntroState::Skipped)
+    }
+}
+
+/// The [IntroState] of the intro screen labelled `L`, so it doesn't interfere with the other
+/// intros in the app.
+#[derive(PartialEq, Clone, Resource, States, Debug, Hash, Eq, Deref)]
+pub struct LabeledIntroState<L>
+where
+    L: IntroLabel,
+{
+    #[deref]
+    state: IntroState,
+    label: PhantomData<L>,
+}
+
+/// A state standing in for `T` in an intro screen, see [IntroLabel::State] and
+/// [IntroLabel::DynamicState].
+///
+/// This is `T` itself for intros without a label and a labelled wrapper of it otherwise.
+pub trait LabeledState<T>: States + FreelyMutableState + Copy + Default + From<T> {
+    /// Returns the state without its label.
+    fn unlabeled(&self) -> T;
+}
+
+impl LabeledState<IntroState> for IntroState {
+    fn unlabeled(&self) -> IntroState {
+        *self
+    }
+}
+
+impl<L> LabeledIntroState<L>
+where
+    L: IntroLabel,
+{
+    /// Labels `state` as the [IntroState] of the intro labelled `L`.
+    pub const fn new(state: IntroState) -> Self {
+        Self {
+            state,
+            label: PhantomData,
+        }
+    }
+}
+
+impl<L> Copy for LabeledIntroState<L> where L: IntroLabel {}
+
+impl<L> Default for LabeledIntroState<L>
+where
+    L: IntroLabel,
+{
+    fn default() -> Self {
+        Self::new(IntroState::default())
+    }
+}
+
+impl<L> From<IntroState> for LabeledIntroState<L>
+where
+    L: IntroLabel,
+{
+    fn from(state: IntroState) -> Self {
+        Self::new(state)
+    }
+}
+
+impl<L> LabeledState<IntroState> for LabeledIntroState<L>
+where
+    L: IntroLabel,
+{
+    fn unlabeled(&self) -> IntroState {
+        self.state
+    }
 }
 
 /// Checks if the splash screen is currently running.
@@ -42,6 +130,16 @@ pub fn is_loading(state: Res<State<IntroState>>) -> bool {
     state.is_loading()
 }
 
+/// Checks if the splash screen is currently running and may be skipped, see [can_skip_labeled].
+pub fn can_skip(
+    state: Res<State<IntroState>>,
+    window: Option<Res<UnskippableWindow>>,
+    slide: Option<Res<CurrentIntroSlide>>,
+    transition: Option<Res<TransitionProgress>>,
+) -> bool {
+    can_skip_labeled::<()>(state, window, slide, transition)
+}
+
 /// Checks if the splash screen has just started running.
 pub fn started_running(state: Res<State<IntroState>>) -> bool {
     state.is_changed() && state.is_running()
@@ -51,3 +149,74 @@ pub fn started_running(state: Res<State<IntroState>>) -> bool {
 pub fn is_failure(state: Res<State<IntroState>>) -> bool {
     state.is_failure()
 }
+
+/// Checks if the splash screen has been skipped.
+pub fn is_skipped(state: Res<State<IntroState>>) -> bool {
+    state.is_skipped()
+}
+
+/// Checks if the splash screen labelled `L` is currently running.
+pub fn is_running_labeled<L: IntroLabel>(state: Res<State<L::State>>) -> bool {
+    state.get().unlabeled().is_running()
+}
+
+/// Checks if the splash screen labelled `L` is currently loading.
+pub fn is_loading_labeled<L: IntroLabel>(state: Res<State<L::State>>) -> bool {
+    state.get().unlabeled().is_loading()
+}
+
+/// Checks if the splash screen labelled `L` is currently running and may be skipped.
+///
+/// This is not the case if the [super::SkipPolicy] is disabled, while the [UnskippableWindow] is
+/// still open, if the current slide of an [super::sequence::IntroSequence] is not skippable or
+/// once the exit transition has started.
+pub fn can_skip_labeled<L: IntroLabel>(
+    state: Res<State<L::State>>,
+    window: Option<Res<UnskippableWindow<L>>>,
+    slide: Option<Res<CurrentIntroSlide<L>>>,
+    transition: Option<Res<TransitionProgress<L>>>,
+) -> bool {
+    let exiting = transition.is_some_and(|transition| {
+        matches!(
+            transition.phase(),
+            TransitionPhase::Exiting | TransitionPhase::Exited
+        )
+    });
+
+    state.get().unlabeled().is_running()
+        && window.is_some_and(|window| window.is_closed())
+        && slide.map_or(true, |slide| *slide.skippable())
+        && !exiting
+}
+
+/// Checks if the splash screen labelled `L` has just started running.
+pub fn started_running_labeled<L: IntroLabel>(state: Res<State<L::State>>) -> bool {
+    state.is_changed() && state.get().unlabeled().is_running()
+}
+
+/// Checks if the splash screen labelled `L` is in a failure state.
+pub fn is_failure_labeled<L: IntroLabel>(state: Res<State<L::State>>) -> bool {
+    state.get().unlabeled().is_failure()
+}
+
+/// Checks if the splash screen labelled `L` has been skipped.
+pub fn is_skipped_labeled<L: IntroLabel>(state: Res<State<L::State>>) -> bool {
+    state.get().unlabeled().is_skipped()
+}
+
+// Whether `run_at` of the intro labelled `L` is entered, the intro state only follows a frame later
+#[derive(Resource)]
+pub(crate) struct InRunAt<L: IntroLabel>(pub(crate) bool, PhantomData<L>);
+
+impl<L> Default for InRunAt<L>
+where
+    L: IntroLabel,
+{
+    fn default() -> Self {
+        Self(false, PhantomData)
+    }
+}
+
+pub(crate) fn in_run_at<L: IntroLabel>(in_run_at: Res<InRunAt<L>>) -> bool {
+    in_run_at.0
+}
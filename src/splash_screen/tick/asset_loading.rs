@@ -0,0 +1,334 @@
+use std::{marker::PhantomData, time::Duration};
+
+use bevy::{
+    asset::{AssetPath, LoadState, RecursiveDependencyLoadState, UntypedHandle},
+    prelude::*,
+    state::state::FreelyMutableState,
+};
+
+use crate::splash_screen::{
+    progress::IntroProgress,
+    reason::{IntroFailure, IntroFailureReason},
+    restart::OnIntroStart,
+    IntroLabel, IntroPreferences, IntroResource, ShowIntroScreen,
+};
+
+use super::{DynamicDurationState, GenericDynamicDuration, IntroDuration};
+
+// Starts loading the assets of an `AssetCollection` and inserts it once everything has loaded
+#[derive(Clone, Copy, Debug)]
+struct CollectionLoader {
+    load: fn(&mut World) -> Vec<UntypedHandle>,
+    insert: fn(&mut World),
+}
+
+/// An intro duration that lasts until a set of assets has finished loading.
+///
+/// The assets (and all of their dependencies) are tracked by [AssetLoadingProgress], which
+/// also accepts handles loaded by the game itself. The intro finishes once everything has
+/// loaded and [AssetLoadingDuration::min_duration] has passed, and fails if any of the assets
+/// fail to load or loading takes longer than [AssetLoadingDuration::max_duration], just like
+/// [GenericDynamicDuration].
+#[derive(Clone, Debug, Resource)]
+#[bon::builder]
+pub struct AssetLoadingDuration {
+    /// Assets loaded every time the intro starts.
+    #[builder(default)]
+    paths: Vec<AssetPath<'static>>,
+    /// How long the intro is shown for at least, even if everything has loaded before.
+    #[builder(default)]
+    min_duration: Duration,
+    /// How long loading may take before the intro fails.
+    max_duration: Duration,
+    #[builder(skip)]
+    collections: Vec<CollectionLoader>,
+}
+
+/// Progress of loading the assets tracked by the [AssetLoadingDuration] of the intro labelled `L`.
+///
+/// The [IntroProgress] of the intro is kept in sync with [AssetLoadingProgress::fraction].
+#[derive(Resource, Clone, Debug)]
+pub struct AssetLoadingProgress<L = ()>
+where
+    L: IntroLabel,
+{
+    handles: Vec<UntypedHandle>,
+    loaded: usize,
+    failed: bool,
+    label: PhantomData<L>,
+}
+
+/// The handles tracked by the [AssetLoadingDuration] of the intro labelled `L` during its last run.
+///
+/// [AssetLoadingProgress] lets go of its handles once the intro ends, they are handed over here
+/// so the assets loaded from [AssetLoadingDuration::builder]'s `paths` stay loaded until the
+/// game has taken what it needs, see [IntroLoadedAssets::take].
+#[derive(Resource, Clone, Debug)]
+pub struct IntroLoadedAssets<L = ()>
+where
+    L: IntroLabel,
+{
+    handles: Vec<UntypedHandle>,
+    label: PhantomData<L>,
+}
+
+impl AssetLoadingDuration {
+    /// Returns how long the intro is shown for at least.
+    pub fn min_duration(&self) -> Duration {
+        self.min_duration
+    }
+
+    /// Returns how long loading may take before the intro fails.
+    pub fn max_duration(&self) -> Duration {
+        self.max_duration
+    }
+
+    /// Loads the [AssetCollection](bevy_asset_loader::prelude::AssetCollection) `T` every time
+    /// the intro starts, inserting it as a resource once all of its assets have loaded.
+    #[cfg(feature = "assets")]
+    pub fn with_collection<T>(mut self) -> Self
+    where
+        T: bevy_asset_loader::prelude::AssetCollection,
+    {
+        self.collections.push(CollectionLoader {
+            load: T::load,
+            insert: |world| {
+                let collection = T::create(world);
+                world.insert_resource(collection);
+            },
+        });
+        self
+    }
+}
+
+impl<L> Default for AssetLoadingProgress<L>
+where
+    L: IntroLabel,
+{
+    fn default() -> Self {
+        Self {
+            handles: Vec::new(),
+            loaded: 0,
+            failed: false,
+            label: PhantomData,
+        }
+    }
+}
+
+impl<L> AssetLoadingProgress<L>
+where
+    L: IntroLabel,
+{
+    /// Tracks `handle` until the intro finishes, keeping the intro shown until it has loaded.
+    ///
+    /// The handle is then handed over to [IntroLoadedAssets].
+    pub fn track(&mut self, handle: impl Into<UntypedHandle>) {
+        self.handles.push(handle.into());
+    }
+
+    /// Returns how many of the tracked assets have loaded, including their dependencies.
+    pub fn loaded(&self) -> usize {
+        self.loaded
+    }
+
+    /// Returns how many assets are tracked.
+    pub fn total(&self) -> usize {
+        self.handles.len()
+    }
+
+    /// Returns how far loading has progressed, from `0.0` to `1.0`.
+    pub fn fraction(&self) -> f32 {
+        match self.handles.is_empty() {
+            true => 1.0,
+            false => self.loaded as f32 / self.handles.len() as f32,
+        }
+    }
+
+    /// Returns `true` once every tracked asset has loaded.
+    pub fn is_loaded(&self) -> bool {
+        self.loaded == self.handles.len()
+    }
+
+    /// Returns `true` if any of the tracked assets failed to load.
+    pub fn is_failed(&self) -> bool {
+        self.failed
+    }
+
+    fn track_new(&mut self, handle: UntypedHandle) {
+        if !self.handles.contains(&handle) {
+            self.handles.push(handle);
+        }
+    }
+}
+
+impl<L> Default for IntroLoadedAssets<L>
+where
+    L: IntroLabel,
+{
+    fn default() -> Self {
+        Self {
+            handles: Vec::new(),
+            label: PhantomData,
+        }
+    }
+}
+
+impl<L> IntroLoadedAssets<L>
+where
+    L: IntroLabel,
+{
+    /// Returns the handles, in the order they were tracked in.
+    pub fn handles(&self) -> &[UntypedHandle] {
+        &self.handles
+    }
+
+    /// Takes the handles, the assets are unloaded once they are dropped.
+    pub fn take(&mut self) -> Vec<UntypedHandle> {
+        std::mem::take(&mut self.handles)
+    }
+}
+
+impl IntroDuration for AssetLoadingDuration {
+    fn configure_duration<S, D, U, L>(
+        &self,
+        app: &mut App,
+        preferences: &IntroPreferences<S, D, U, L>,
+    ) where
+        S: States + FreelyMutableState,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        GenericDynamicDuration::new(self.max_duration)
+            .with_min_duration(self.min_duration)
+            .configure_duration(app, preferences);
+
+        app.insert_resource(IntroResource::<_, L>::new(self.clone()))
+            .init_resource::<AssetLoadingProgress<L>>()
+            .init_resource::<IntroLoadedAssets<L>>()
+            .add_systems(
+                OnIntroStart::<L>::default(),
+                (start_loading::<L>, start_loading_collections::<L>).chain(),
+            )
+            .add_systems(
+                Update,
+                Self::only_run_if_labeled::<L, _>(update_progress::<L>),
+            )
+            .add_systems(
+                OnEnter(L::DynamicState::from(DynamicDurationState::Completed)),
+                insert_collections::<L>,
+            )
+            .add_systems(OnExit(preferences.run_at.clone()), stop_tracking::<L>);
+    }
+}
+
+// A restarted intro still tracks the assets of the failed attempt, those that failed are dropped
+// so they are loaded again and the others aren't tracked twice
+fn start_loading<L: IntroLabel>(
+    mut progress: ResMut<AssetLoadingProgress<L>>,
+    duration: Res<IntroResource<AssetLoadingDuration, L>>,
+    asset_server: Res<AssetServer>,
+) {
+    progress.handles.retain(|handle| {
+        asset_server.get_recursive_dependency_load_state(handle.id())
+            != Some(RecursiveDependencyLoadState::Failed)
+    });
+    progress.loaded = 0;
+    progress.failed = false;
+
+    for path in duration.paths.iter() {
+        progress.track_new(asset_server.load_untyped(path.clone()).untyped());
+    }
+}
+
+fn start_loading_collections<L: IntroLabel>(world: &mut World) {
+    let collections = world
+        .resource::<IntroResource<AssetLoadingDuration, L>>()
+        .collections
+        .clone();
+
+    for collection in collections {
+        let handles = (collection.load)(world);
+        let mut progress = world.resource_mut::<AssetLoadingProgress<L>>();
+        for handle in handles {
+            progress.track_new(handle);
+        }
+    }
+}
+
+fn update_progress<L: IntroLabel>(
+    mut progress: ResMut<AssetLoadingProgress<L>>,
+    mut intro_progress: ResMut<IntroProgress<L>>,
+    mut failure: ResMut<IntroFailure<L>>,
+    mut next_state: ResMut<NextState<L::DynamicState>>,
+    asset_server: Res<AssetServer>,
+) {
+    let states = progress
+        .handles
+        .iter()
+        .map(|handle| asset_server.get_recursive_dependency_load_state(handle.id()));
+
+    // Assets the server doesn't know about were added directly and are already there
+    let (loaded, failed) = states.fold((0, false), |(loaded, failed), state| match state {
+        Some(RecursiveDependencyLoadState::Loaded) | None => (loaded + 1, failed),
+        Some(RecursiveDependencyLoadState::Failed) => (loaded, true),
+        Some(_) => (loaded, failed),
+    });
+
+    progress.loaded = loaded;
+    progress.failed = failed;
+    intro_progress.set(progress.fraction());
+
+    if failed {
+        if let Some(reason) = failed_asset(&progress, &asset_server) {
+            failure.report(reason);
+        }
+        next_state.set(DynamicDurationState::Failure.into());
+    } else if progress.is_loaded() {
+        next_state.set(DynamicDurationState::Completed.into());
+    }
+}
+
+// Only the asset itself knows why it failed, a failed dependency is reported without an error
+fn failed_asset<L: IntroLabel>(
+    progress: &AssetLoadingProgress<L>,
+    asset_server: &AssetServer,
+) -> Option<IntroFailureReason> {
+    let handle = progress.handles.iter().find(|handle| {
+        asset_server.get_recursive_dependency_load_state(handle.id())
+            == Some(RecursiveDependencyLoadState::Failed)
+    })?;
+
+    let error = match asset_server.get_load_state(handle.id()) {
+        Some(LoadState::Failed(error)) => error.to_string(),
+        _ => String::from("a dependency failed to load"),
+    };
+
+    Some(IntroFailureReason::AssetLoad {
+        // Untyped handles are loaded from a source of their own, which isn't part of the path
+        path: handle.path().map(|path| path.path().display().to_string()),
+        error,
+    })
+}
+
+fn insert_collections<L: IntroLabel>(world: &mut World) {
+    let collections = world
+        .resource::<IntroResource<AssetLoadingDuration, L>>()
+        .collections
+        .clone();
+
+    for collection in collections {
+        (collection.insert)(world);
+    }
+}
+
+// Handles are kept while the intro is shown and handed over once it's over, the previous run's
+// handles are dropped at that point
+fn stop_tracking<L: IntroLabel>(
+    mut progress: ResMut<AssetLoadingProgress<L>>,
+    mut loaded_assets: ResMut<IntroLoadedAssets<L>>,
+) {
+    loaded_assets.handles = std::mem::take(&mut progress.handles);
+    progress.loaded = 0;
+    progress.failed = false;
+}
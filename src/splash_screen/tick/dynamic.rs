@@ -1,14 +1,21 @@
-use std::time::Duration;
+use std::{marker::PhantomData, time::Duration};
 
 use bevy::{prelude::*, state::state::FreelyMutableState};
 
-use crate::splash_screen::{ShowIntroScreen, IntroPreferences, IntroState};
+use crate::splash_screen::{
+    reason::{IntroFailure, IntroFailureReason},
+    restart::OnIntroStart,
+    state::LabeledState,
+    time::IntroTime,
+    IntroLabel, IntroPreferences, IntroResource, IntroState, ShowIntroScreen,
+};
 
 use super::{FixedDuration, IntroDuration};
 
 /// Represents the possible states of a dynamic duration.
-#[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
-
+///
+/// Intros with a label follow their own state instead, see [IntroLabel::DynamicState].
+#[derive(States, Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
 pub enum DynamicDurationState {
     /// Indicates a failure during the dynamic duration calculation.
     Failure,
@@ -19,15 +26,86 @@ pub enum DynamicDurationState {
     Running,
 }
 
+/// The [DynamicDurationState] of the intro screen labelled `L`, see [crate::prelude::LabeledIntroState].
+#[derive(PartialEq, Clone, States, Debug, Hash, Eq, Deref)]
+pub struct LabeledDynamicDurationState<L>
+where
+    L: IntroLabel,
+{
+    #[deref]
+    state: DynamicDurationState,
+    label: PhantomData<L>,
+}
+
+impl LabeledState<DynamicDurationState> for DynamicDurationState {
+    fn unlabeled(&self) -> DynamicDurationState {
+        *self
+    }
+}
+
+impl<L> LabeledDynamicDurationState<L>
+where
+    L: IntroLabel,
+{
+    /// Labels `state` as the [DynamicDurationState] of the intro labelled `L`.
+    pub const fn new(state: DynamicDurationState) -> Self {
+        Self {
+            state,
+            label: PhantomData,
+        }
+    }
+}
+
+impl<L> Copy for LabeledDynamicDurationState<L> where L: IntroLabel {}
+
+impl<L> Default for LabeledDynamicDurationState<L>
+where
+    L: IntroLabel,
+{
+    fn default() -> Self {
+        Self::new(DynamicDurationState::default())
+    }
+}
+
+impl<L> From<DynamicDurationState> for LabeledDynamicDurationState<L>
+where
+    L: IntroLabel,
+{
+    fn from(state: DynamicDurationState) -> Self {
+        Self::new(state)
+    }
+}
+
+impl<L> LabeledState<DynamicDurationState> for LabeledDynamicDurationState<L>
+where
+    L: IntroLabel,
+{
+    fn unlabeled(&self) -> DynamicDurationState {
+        self.state
+    }
+}
+
 /// Represents a generic dynamic duration with a maximum duration.
 ///
 /// Provides a basic implementation for dynamic durations by initializing the state and starting a maximum duration countdown.
 /// Applications should implement logic to transition from `Running` to either `Failure` or `Completed` based on specific conditions.
-
+///
+/// If the state is `Completed` before [GenericDynamicDuration::with_min_duration] has passed, the
+/// intro is still shown until it has, while `Failure` always ends the intro right away.
+///
+/// The state is set back to `Running` once [IntroPreferences::run_at] is left, so it can already
+/// be set in the `OnEnter` schedule of `run_at`. Restarting the intro, e.g. using
+/// [crate::prelude::OnFailureRetry], only sets it back if it is `Failure`.
+///
+/// The other dynamic durations, like [super::AssetLoadingDuration], [super::StagedDuration] and
+/// [super::TaskDuration], configure a `GenericDynamicDuration` of their own, which handles their
+/// minimum and maximum durations and the switch to [IntroPreferences::transition_to].
 #[derive(Clone, Debug, Resource)]
 pub struct GenericDynamicDuration {
     /// Maximum allowed duration for the dynamic process.
     max_duration: FixedDuration<DynamicDurationState>,
+    /// Minimum time the intro is shown for, even if the dynamic process completes earlier.
+    min_duration: Timer,
 }
 
 impl GenericDynamicDuration {
@@ -42,7 +120,26 @@ impl GenericDynamicDuration {
         let max_duration =
             FixedDuration::new_with_duration(max_duration.into(), DynamicDurationState::Failure);
 
-        Self { max_duration }
+        Self {
+            max_duration,
+            min_duration: Timer::new(Duration::ZERO, TimerMode::Once),
+        }
+    }
+
+    /// Shows the intro for at least `min_duration`, even if the dynamic process completes earlier.
+    pub fn with_min_duration(mut self, min_duration: impl Into<Duration>) -> Self {
+        self.min_duration = Timer::new(min_duration.into(), TimerMode::Once);
+        self
+    }
+
+    /// Returns the minimum time the intro is shown for.
+    pub fn min_duration(&self) -> Duration {
+        self.min_duration.duration()
+    }
+
+    /// Returns the maximum time the dynamic process may take before it fails.
+    pub fn max_duration(&self) -> Duration {
+        self.max_duration.duration()
     }
 }
 
@@ -53,42 +150,115 @@ impl AsRef<FixedDuration<DynamicDurationState>> for GenericDynamicDuration {
 }
 
 impl IntroDuration for GenericDynamicDuration {
-    fn configure_duration<S, D, U>(&self, app: &mut App, preferences: &IntroPreferences<S, D, U>)
-    where
+    fn configure_duration<S, D, U, L>(
+        &self,
+        app: &mut App,
+        preferences: &IntroPreferences<S, D, U, L>,
+    ) where
         S: States + FreelyMutableState,
         D: IntroDuration,
         U: ShowIntroScreen,
+        L: IntroLabel,
     {
-        app.init_state::<DynamicDurationState>();
-        app.insert_resource(self.clone());
+        app.init_state::<L::DynamicState>();
+        app.insert_resource(IntroResource::<_, L>::new(self.clone()));
 
         app.add_systems(
             Update,
-            Self::only_run_if(
-                finish_splash::<S, D, U>.run_if(in_state(DynamicDurationState::Completed)),
+            Self::only_run_if_labeled::<L, _>(
+                (
+                    tick_min_duration::<L>,
+                    finish_splash::<S, D, U, L>
+                        .run_if(in_state(L::DynamicState::from(
+                            DynamicDurationState::Completed,
+                        )))
+                        .run_if(min_duration_passed::<L>),
+                )
+                    .chain(),
             ),
         );
 
         app.add_systems(
             Update,
-            Self::only_run_if(change_to_failure.run_if(in_state(DynamicDurationState::Failure))),
+            Self::only_run_if_labeled::<L, _>(change_to_failure::<L>.run_if(in_state(
+                L::DynamicState::from(DynamicDurationState::Failure),
+            ))),
         );
 
-        self.max_duration.configure_duration(app, preferences);
+        app.add_systems(
+            OnIntroStart::<L>::default(),
+            (reset_failed_dynamic_state::<L>, reset_min_duration::<L>),
+        )
+        .add_systems(OnExit(preferences.run_at.clone()), reset_dynamic_state::<L>);
+
+        app.add_systems(
+            OnEnter(L::DynamicState::from(DynamicDurationState::Failure)),
+            report_timeout::<L>,
+        );
+
+        // The maximum duration fails the dynamic state of this intro's label
+        FixedDuration::new_with_duration(
+            self.max_duration.duration(),
+            L::DynamicState::from(DynamicDurationState::Failure),
+        )
+        .configure_duration(app, preferences);
     }
 }
 
-fn finish_splash<S, D, U>(
+fn tick_min_duration<L: IntroLabel>(
+    mut duration: ResMut<IntroResource<GenericDynamicDuration, L>>,
+    time: Res<IntroTime<L>>,
+) {
+    duration.min_duration.tick(time.delta());
+}
+
+fn min_duration_passed<L: IntroLabel>(
+    duration: Res<IntroResource<GenericDynamicDuration, L>>,
+) -> bool {
+    duration.min_duration.finished()
+}
+
+fn reset_min_duration<L: IntroLabel>(
+    mut duration: ResMut<IntroResource<GenericDynamicDuration, L>>,
+) {
+    duration.min_duration.reset();
+}
+
+fn finish_splash<S, D, U, L>(
     mut next_state: ResMut<NextState<S>>,
-    preferences: Res<IntroPreferences<S, D, U>>,
+    preferences: Res<IntroPreferences<S, D, U, L>>,
 ) where
     S: bevy::prelude::States + FreelyMutableState + Clone,
     D: IntroDuration,
     U: ShowIntroScreen,
+    L: IntroLabel,
 {
     next_state.set(preferences.transition_to.clone())
 }
 
-fn change_to_failure(mut next_state: ResMut<NextState<IntroState>>) {
-    next_state.set(IntroState::Failure)
+fn change_to_failure<L: IntroLabel>(mut next_state: ResMut<NextState<L::State>>) {
+    next_state.set(IntroState::Failure.into())
+}
+
+// Other failures of the dynamic state report their own reason before it is entered
+fn report_timeout<L: IntroLabel>(
+    mut failure: ResMut<IntroFailure<L>>,
+    max_duration: Res<IntroResource<FixedDuration<L::DynamicState>, L>>,
+) {
+    if max_duration.is_finished() {
+        failure.report(IntroFailureReason::Timeout(max_duration.duration()));
+    }
+}
+
+fn reset_dynamic_state<L: IntroLabel>(mut next_state: ResMut<NextState<L::DynamicState>>) {
+    next_state.set(DynamicDurationState::Running.into())
+}
+
+fn reset_failed_dynamic_state<L: IntroLabel>(
+    state: Res<State<L::DynamicState>>,
+    next_state: ResMut<NextState<L::DynamicState>>,
+) {
+    if state.get().unlabeled() == DynamicDurationState::Failure {
+        reset_dynamic_state::<L>(next_state);
+    }
 }
@@ -1,7 +1,9 @@
 use bevy::{prelude::*, state::state::FreelyMutableState, time::Timer};
 use std::time::Duration;
 
-use crate::splash_screen::{ShowIntroScreen, IntroPreferences};
+use crate::splash_screen::{
+    restart::OnIntroStart, time::IntroTime, IntroLabel, IntroPreferences, IntroResource, ShowIntroScreen,
+};
 
 use super::IntroDuration;
 
@@ -33,20 +35,37 @@ where
         const SPLASH_DEFAULT_DURATION: Duration = Duration::from_millis(1500);
         Self::new_with_duration(SPLASH_DEFAULT_DURATION, next_state)
     }
+
+    /// Returns how long the countdown takes.
+    pub fn duration(&self) -> Duration {
+        self.timer.duration()
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        self.timer.finished()
+    }
 }
 
 impl<S> IntroDuration for FixedDuration<S>
 where
     S: States + FreelyMutableState,
 {
-    fn configure_duration<_State, D, U>(&self, app: &mut App, _: &IntroPreferences<_State, D, U>)
-    where
+    fn configure_duration<_State, D, U, L>(
+        &self,
+        app: &mut App,
+        _preferences: &IntroPreferences<_State, D, U, L>,
+    ) where
         _State: States + FreelyMutableState,
         D: IntroDuration,
         U: ShowIntroScreen,
+        L: IntroLabel,
     {
-        app.insert_resource(self.clone())
-            .add_systems(Update, Self::only_run_if(Self::tick_time));
+        app.insert_resource(IntroResource::<_, L>::new(self.clone()))
+            .add_systems(
+                Update,
+                Self::only_run_if_labeled::<L, _>(Self::tick_time::<L>),
+            )
+            .add_systems(OnIntroStart::<L>::default(), Self::reset_time::<L>);
     }
 }
 
@@ -54,18 +73,23 @@ impl<S> FixedDuration<S>
 where
     S: States + FreelyMutableState,
 {
-    /// Updates the internal timer based on the delta time.
+    /// Updates the internal timer based on the [IntroTime] of the intro.
     ///
     /// This system is only run when the splash screen is active.
     /// If the timer reaches zero, transitions to the specified next state.
-    fn tick_time(
+    fn tick_time<L: IntroLabel>(
         mut next_state: ResMut<NextState<S>>,
-        mut countdown: ResMut<Self>,
-        time: Res<Time>,
+        mut countdown: ResMut<IntroResource<Self, L>>,
+        time: Res<IntroTime<L>>,
     ) {
         countdown.timer.tick(time.delta());
         if countdown.timer.finished() {
             next_state.set(countdown.next_state.clone());
         }
     }
+
+    /// Rewinds the internal timer so the countdown starts over every time the splash screen starts.
+    fn reset_time<L: IntroLabel>(mut countdown: ResMut<IntroResource<Self, L>>) {
+        countdown.timer.reset();
+    }
 }
@@ -1,25 +1,56 @@
+mod asset_loading;
 mod dynamic;
 mod fixed;
+mod staged;
+#[cfg(feature = "tasks")]
+mod task;
 
+pub use asset_loading::*;
 pub use dynamic::*;
 pub use fixed::*;
+pub use staged::*;
+#[cfg(feature = "tasks")]
+pub use task::*;
 
 use bevy::{ecs::schedule::SystemConfigs, prelude::*, state::state::FreelyMutableState};
 
-use super::{state::is_running, ShowIntroScreen, IntroPreferences};
+use super::{
+    label::IntroLabel,
+    state::{in_run_at, is_running_labeled},
+    IntroPreferences, ShowIntroScreen,
+};
 
 /// Defines a trait for configuring the splash screen duration.
 pub trait IntroDuration: Resource + Clone {
     /// Configures the splash screen based on the provided `IntroPreferences`.
-    fn configure_duration<S, D, U>(&self, app: &mut App, preferences: &IntroPreferences<S, D, U>)
-    where
+    fn configure_duration<S, D, U, L>(
+        &self,
+        app: &mut App,
+        preferences: &IntroPreferences<S, D, U, L>,
+    ) where
         S: States + FreelyMutableState,
         D: IntroDuration,
-        U: ShowIntroScreen;
+        U: ShowIntroScreen,
+        L: IntroLabel;
 
     /// This helper function should be used for systems added using this trait to guarantee
     /// that they are executed only when necessary.
+    ///
+    /// Only use this for intros without a label, see [IntroDuration::only_run_if_labeled].
     fn only_run_if<M>(value: impl IntoSystemConfigs<M>) -> SystemConfigs {
-        value.run_if(is_running)
+        Self::only_run_if_labeled::<(), M>(value)
+    }
+
+    /// Like [IntroDuration::only_run_if], for the systems of the intro labelled `L`.
+    ///
+    /// The systems also stop as soon as [IntroPreferences::run_at] is left, as the intro state
+    /// labelled `L` only follows a frame later.
+    fn only_run_if_labeled<L, M>(value: impl IntoSystemConfigs<M>) -> SystemConfigs
+    where
+        L: IntroLabel,
+    {
+        value
+            .run_if(is_running_labeled::<L>)
+            .run_if(in_run_at::<L>)
     }
 }
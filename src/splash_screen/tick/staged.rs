@@ -0,0 +1,227 @@
+use std::{borrow::Cow, marker::PhantomData, time::Duration};
+
+use bevy::{prelude::*, state::state::FreelyMutableState};
+
+use crate::splash_screen::{
+    progress::IntroProgress, IntroLabel, IntroPreferences, ShowIntroScreen,
+};
+
+use super::{DynamicDurationState, GenericDynamicDuration, IntroDuration};
+
+/// An intro duration that lasts until a set of named stages, like loading shaders or audio
+/// banks, have completed.
+///
+/// The game reports the progress of every stage through [IntroStages], or using
+/// [complete_stage] as a system. Every stage has a weight describing how much of the overall
+/// work it is, which is used to compute the [IntroProgress] of the intro. The name of the first
+/// stage that hasn't completed yet is shown as the message of the progress whenever the current
+/// stage changes, so the message can still be replaced in between.
+///
+/// The minimum and maximum durations work just like in [GenericDynamicDuration].
+#[derive(Clone, Debug, Resource)]
+pub struct StagedDuration {
+    stages: Vec<IntroStage>,
+    dynamic: GenericDynamicDuration,
+}
+
+/// A stage of a [StagedDuration].
+#[derive(Clone, Debug, PartialEq)]
+pub struct IntroStage {
+    name: Cow<'static, str>,
+    weight: f32,
+    fraction: f32,
+}
+
+/// The stages of the [StagedDuration] of the intro labelled `L`.
+///
+/// The progress of every stage is reset once [IntroPreferences::run_at] is left, so stages can
+/// already be completed in the `OnEnter` schedule of `run_at`. Stages completed before the intro
+/// is restarted, e.g. by [crate::prelude::OnFailureRetry], stay completed.
+#[derive(Resource, Clone, Debug)]
+pub struct IntroStages<L = ()>
+where
+    L: IntroLabel,
+{
+    stages: Vec<IntroStage>,
+    // The stage whose name is shown as the message of the progress
+    shown: Option<usize>,
+    label: PhantomData<L>,
+}
+
+impl StagedDuration {
+    /// Creates a new `StagedDuration` without any stages, that fails after `max_duration`.
+    pub fn new(max_duration: impl Into<Duration>) -> Self {
+        Self {
+            stages: Vec::new(),
+            dynamic: GenericDynamicDuration::new(max_duration),
+        }
+    }
+
+    /// Shows the intro for at least `min_duration`, even if every stage completes earlier.
+    pub fn with_min_duration(mut self, min_duration: impl Into<Duration>) -> Self {
+        self.dynamic = self.dynamic.with_min_duration(min_duration);
+        self
+    }
+
+    /// Adds a stage named `name`, making up `weight` of the overall work relative to the other
+    /// stages.
+    ///
+    /// Stages are expected to complete in the order they are added, as the first stage that
+    /// hasn't completed yet is considered the current one.
+    pub fn with_stage(mut self, name: impl Into<Cow<'static, str>>, weight: f32) -> Self {
+        self.stages.push(IntroStage {
+            name: name.into(),
+            weight: weight.max(0.0),
+            fraction: 0.0,
+        });
+        self
+    }
+}
+
+impl IntroStage {
+    /// Returns the name of the stage.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns how much of the overall work the stage is, relative to the other stages.
+    pub fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    /// Returns how far along the stage is, from `0.0` to `1.0`.
+    pub fn fraction(&self) -> f32 {
+        self.fraction
+    }
+
+    /// Returns `true` once the stage has completed.
+    pub fn is_completed(&self) -> bool {
+        self.fraction >= 1.0
+    }
+}
+
+impl<L> IntroStages<L>
+where
+    L: IntroLabel,
+{
+    /// Returns the stages, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &IntroStage> {
+        self.stages.iter()
+    }
+
+    /// Returns the stage named `name`.
+    pub fn get(&self, name: &str) -> Option<&IntroStage> {
+        self.stages.iter().find(|stage| stage.name == name)
+    }
+
+    /// Returns the first stage that hasn't completed yet.
+    pub fn current(&self) -> Option<&IntroStage> {
+        self.stages.iter().find(|stage| !stage.is_completed())
+    }
+
+    /// Sets how far along the stage named `name` is, clamped to `0.0..=1.0`.
+    ///
+    /// Stages that don't exist are ignored.
+    pub fn set_progress(&mut self, name: &str, fraction: f32) {
+        if let Some(stage) = self.stages.iter_mut().find(|stage| stage.name == name) {
+            stage.fraction = fraction.clamp(0.0, 1.0);
+        }
+    }
+
+    /// Completes the stage named `name`.
+    ///
+    /// Stages that don't exist are ignored.
+    pub fn complete(&mut self, name: &str) {
+        self.set_progress(name, 1.0);
+    }
+
+    /// Returns how far along all of the stages are according to their weights, from `0.0` to `1.0`.
+    pub fn fraction(&self) -> f32 {
+        let total: f32 = self.stages.iter().map(IntroStage::weight).sum();
+        match total > 0.0 {
+            true => {
+                let done: f32 = self
+                    .stages
+                    .iter()
+                    .map(|stage| stage.weight * stage.fraction)
+                    .sum();
+                done / total
+            }
+            false => match self.is_completed() {
+                true => 1.0,
+                false => 0.0,
+            },
+        }
+    }
+
+    /// Returns `true` once every stage has completed.
+    pub fn is_completed(&self) -> bool {
+        self.stages.iter().all(IntroStage::is_completed)
+    }
+}
+
+/// Returns a system completing the stage named `name` of the intro labelled `L`.
+///
+/// ```rust,ignore
+/// app.add_systems(OnEnter(AudioState::Loaded), complete_stage::<()>("Loading audio"));
+/// ```
+pub fn complete_stage<L: IntroLabel>(
+    name: impl Into<Cow<'static, str>>,
+) -> impl FnMut(ResMut<IntroStages<L>>) {
+    let name = name.into();
+    move |mut stages| stages.complete(&name)
+}
+
+impl IntroDuration for StagedDuration {
+    fn configure_duration<S, D, U, L>(
+        &self,
+        app: &mut App,
+        preferences: &IntroPreferences<S, D, U, L>,
+    ) where
+        S: States + FreelyMutableState,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        self.dynamic.configure_duration(app, preferences);
+
+        app.insert_resource(IntroStages::<L> {
+            stages: self.stages.clone(),
+            shown: None,
+            label: PhantomData,
+        })
+        .add_systems(OnExit(preferences.run_at.clone()), reset_stages::<L>)
+        .add_systems(
+            Update,
+            Self::only_run_if_labeled::<L, _>(update_stages::<L>),
+        );
+    }
+}
+
+fn reset_stages<L: IntroLabel>(mut stages: ResMut<IntroStages<L>>) {
+    for stage in stages.stages.iter_mut() {
+        stage.fraction = 0.0;
+    }
+    stages.shown = None;
+}
+
+fn update_stages<L: IntroLabel>(
+    mut stages: ResMut<IntroStages<L>>,
+    mut progress: ResMut<IntroProgress<L>>,
+    mut next_state: ResMut<NextState<L::DynamicState>>,
+) {
+    progress.set(stages.fraction());
+
+    let current = stages.stages.iter().position(|stage| !stage.is_completed());
+    if current != stages.shown {
+        stages.shown = current;
+        match current {
+            Some(index) => progress.set_message(stages.stages[index].name.clone()),
+            None => progress.clear_message(),
+        }
+    }
+
+    if current.is_none() {
+        next_state.set(DynamicDurationState::Completed.into());
+    }
+}
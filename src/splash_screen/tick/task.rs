@@ -0,0 +1,301 @@
+use std::{
+    borrow::Cow,
+    fmt,
+    future::Future,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use bevy::{
+    prelude::*,
+    state::state::FreelyMutableState,
+    tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task},
+    utils::BoxedFuture,
+};
+
+use crate::splash_screen::{
+    progress::IntroProgress,
+    reason::{IntroFailure, IntroFailureReason},
+    restart::OnIntroStart,
+    IntroLabel, IntroPreferences, IntroResource, IntroState, ShowIntroScreen,
+};
+
+use super::{DynamicDurationState, GenericDynamicDuration, IntroDuration};
+
+/// The result of a task run by a [TaskDuration], the error is the reason the task failed.
+pub type IntroTaskResult = Result<(), String>;
+
+type TaskFactory = dyn Fn(IntroTaskProgress) -> BoxedFuture<'static, IntroTaskResult> + Send + Sync;
+
+/// An intro duration that lasts until a set of background tasks have finished.
+///
+/// The tasks are spawned on the [AsyncComputeTaskPool] once the intro starts running, so they
+/// don't block rendering of the intro screen. This requires the `tasks` feature, which enables
+/// bevy's `multi_threaded` feature. The intro finishes once every task has succeeded and fails
+/// as soon as one of them returns an error, with the minimum and maximum durations working just
+/// like in [GenericDynamicDuration].
+///
+/// The state of every task is available through [IntroTasks], and their combined progress is
+/// reported as the [IntroProgress] of the intro.
+#[derive(Clone, Resource)]
+pub struct TaskDuration {
+    tasks: Vec<(Cow<'static, str>, Arc<TaskFactory>)>,
+    dynamic: GenericDynamicDuration,
+}
+
+/// Lets a task run by a [TaskDuration] report how far along it is.
+#[derive(Clone, Debug, Default)]
+pub struct IntroTaskProgress(Arc<AtomicU32>);
+
+/// The state of a task run by a [TaskDuration].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntroTaskState {
+    /// The task is still running.
+    Running,
+    /// The task finished successfully.
+    Completed,
+    /// The task failed for the given reason.
+    Failed(String),
+}
+
+/// A task run by the [TaskDuration] of an intro, see [IntroTasks].
+#[derive(Debug)]
+pub struct IntroTaskStatus {
+    name: Cow<'static, str>,
+    progress: IntroTaskProgress,
+    state: IntroTaskState,
+    // Taken once the task is done
+    task: Option<Task<IntroTaskResult>>,
+}
+
+/// The tasks run by the [TaskDuration] of the intro labelled `L`.
+///
+/// The tasks are cancelled once the intro finishes, tasks that are still running at that point
+/// are dropped at their next `.await`.
+#[derive(Resource, Debug)]
+pub struct IntroTasks<L = ()>
+where
+    L: IntroLabel,
+{
+    tasks: Vec<IntroTaskStatus>,
+    label: PhantomData<L>,
+}
+
+impl TaskDuration {
+    /// Creates a new `TaskDuration` without any tasks, that fails after `max_duration`.
+    pub fn new(max_duration: impl Into<Duration>) -> Self {
+        Self {
+            tasks: Vec::new(),
+            dynamic: GenericDynamicDuration::new(max_duration),
+        }
+    }
+
+    /// Shows the intro for at least `min_duration`, even if every task finishes earlier.
+    pub fn with_min_duration(mut self, min_duration: impl Into<Duration>) -> Self {
+        self.dynamic = self.dynamic.with_min_duration(min_duration);
+        self
+    }
+
+    /// Runs the future returned by `task` every time the intro starts running.
+    pub fn with_task<F, Fut>(mut self, name: impl Into<Cow<'static, str>>, task: F) -> Self
+    where
+        F: Fn(IntroTaskProgress) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = IntroTaskResult> + Send + 'static,
+    {
+        let factory: Arc<TaskFactory> = Arc::new(move |progress| Box::pin(task(progress)));
+        self.tasks.push((name.into(), factory));
+        self
+    }
+
+    /// Runs the blocking closure `task` every time the intro starts running.
+    ///
+    /// The closure blocks one of the threads of the [AsyncComputeTaskPool] until it returns, so it
+    /// can't be cancelled once it has started.
+    pub fn with_blocking_task<F>(self, name: impl Into<Cow<'static, str>>, task: F) -> Self
+    where
+        F: Fn(IntroTaskProgress) -> IntroTaskResult + Clone + Send + Sync + 'static,
+    {
+        self.with_task(name, move |progress| {
+            let task = task.clone();
+            async move { task(progress) }
+        })
+    }
+}
+
+impl fmt::Debug for TaskDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskDuration")
+            .field(
+                "tasks",
+                &self.tasks.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            )
+            .field("dynamic", &self.dynamic)
+            .finish()
+    }
+}
+
+impl IntroTaskProgress {
+    /// Sets how far along the task is, clamped to `0.0..=1.0`.
+    pub fn set(&self, fraction: f32) {
+        self.0
+            .store(fraction.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+    }
+
+    /// Returns how far along the task is, from `0.0` to `1.0`.
+    pub fn get(&self) -> f32 {
+        f32::from_bits(self.0.load(Ordering::Relaxed))
+    }
+}
+
+impl IntroTaskStatus {
+    /// Returns the name the task was added with.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns how far along the task is, from `0.0` to `1.0`.
+    pub fn fraction(&self) -> f32 {
+        match self.state {
+            IntroTaskState::Completed => 1.0,
+            _ => self.progress.get(),
+        }
+    }
+
+    /// Returns the state of the task.
+    pub fn state(&self) -> &IntroTaskState {
+        &self.state
+    }
+}
+
+impl<L> Default for IntroTasks<L>
+where
+    L: IntroLabel,
+{
+    fn default() -> Self {
+        Self {
+            tasks: Vec::new(),
+            label: PhantomData,
+        }
+    }
+}
+
+impl<L> IntroTasks<L>
+where
+    L: IntroLabel,
+{
+    /// Returns the tasks of the intro, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &IntroTaskStatus> {
+        self.tasks.iter()
+    }
+
+    /// Returns how far along all of the tasks are, from `0.0` to `1.0`.
+    pub fn fraction(&self) -> f32 {
+        match self.tasks.is_empty() {
+            true => 1.0,
+            false => {
+                let total: f32 = self.tasks.iter().map(IntroTaskStatus::fraction).sum();
+                total / self.tasks.len() as f32
+            }
+        }
+    }
+}
+
+impl IntroDuration for TaskDuration {
+    fn configure_duration<S, D, U, L>(
+        &self,
+        app: &mut App,
+        preferences: &IntroPreferences<S, D, U, L>,
+    ) where
+        S: States + FreelyMutableState,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        self.dynamic.configure_duration(app, preferences);
+
+        app.insert_resource(IntroResource::<_, L>::new(self.clone()))
+            .init_resource::<IntroTasks<L>>()
+            .add_systems(OnIntroStart::<L>::default(), cancel_tasks::<L>)
+            .add_systems(
+                OnEnter(L::State::from(IntroState::Running)),
+                spawn_tasks::<L>,
+            )
+            .add_systems(Update, Self::only_run_if_labeled::<L, _>(poll_tasks::<L>))
+            .add_systems(OnExit(preferences.run_at.clone()), cancel_tasks::<L>);
+    }
+}
+
+// Slides of a sequence enter `Running` again, but the tasks only run once per intro
+fn spawn_tasks<L: IntroLabel>(
+    mut tasks: ResMut<IntroTasks<L>>,
+    duration: Res<IntroResource<TaskDuration, L>>,
+) {
+    if !tasks.tasks.is_empty() {
+        return;
+    }
+
+    let pool = AsyncComputeTaskPool::get();
+    tasks.tasks = duration
+        .tasks
+        .iter()
+        .map(|(name, factory)| {
+            let progress = IntroTaskProgress::default();
+            let task = pool.spawn(factory(progress.clone()));
+
+            IntroTaskStatus {
+                name: name.clone(),
+                progress,
+                state: IntroTaskState::Running,
+                task: Some(task),
+            }
+        })
+        .collect();
+}
+
+fn poll_tasks<L: IntroLabel>(
+    mut tasks: ResMut<IntroTasks<L>>,
+    mut intro_progress: ResMut<IntroProgress<L>>,
+    mut failure: ResMut<IntroFailure<L>>,
+    mut next_state: ResMut<NextState<L::DynamicState>>,
+) {
+    for status in tasks.tasks.iter_mut() {
+        let Some(task) = status.task.as_mut() else {
+            continue;
+        };
+
+        if let Some(result) = block_on(future::poll_once(task)) {
+            status.task = None;
+            status.state = match result {
+                Ok(()) => IntroTaskState::Completed,
+                Err(reason) => {
+                    failure.report(IntroFailureReason::Task {
+                        name: status.name.to_string(),
+                        error: reason.clone(),
+                    });
+                    IntroTaskState::Failed(reason)
+                }
+            };
+        }
+    }
+
+    intro_progress.set(tasks.fraction());
+
+    let mut states = tasks.tasks.iter().map(IntroTaskStatus::state);
+    if states
+        .clone()
+        .any(|state| matches!(state, IntroTaskState::Failed(_)))
+    {
+        next_state.set(DynamicDurationState::Failure.into());
+    } else if states.all(|state| state == &IntroTaskState::Completed) {
+        next_state.set(DynamicDurationState::Completed.into());
+    }
+}
+
+// Dropping a task cancels it
+fn cancel_tasks<L: IntroLabel>(mut tasks: ResMut<IntroTasks<L>>) {
+    tasks.tasks.clear();
+}
@@ -0,0 +1,159 @@
+use std::{marker::PhantomData, time::Duration};
+
+use bevy::{prelude::*, state::state::FreelyMutableState, window::PrimaryWindow};
+
+use super::{label::IntroLabel, IntroDuration, IntroPreferences, ShowIntroScreen};
+
+/// The clock the countdowns of an intro are driven by, see [IntroTime].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum IntroTimeSource {
+    /// [Time<Virtual>], so the intro stops along with the game when virtual time is paused or
+    /// slowed down.
+    #[default]
+    Virtual,
+    /// [Time<Real>], so the intro keeps going no matter what happens to virtual time.
+    Real,
+}
+
+/// The clock driving the countdowns of the intro labelled `L`, like [super::tick::FixedDuration],
+/// the minimum and maximum durations and the slides of an [super::sequence::IntroSequence].
+///
+/// The clock can be paused, sped up or slowed down and extended at runtime, and is paused
+/// automatically while the primary window is unfocused if
+/// [IntroPreferences::pause_when_unfocused] is set. Pausing and extending only apply to the
+/// current run of the intro, while the speed is kept.
+#[derive(Resource, Clone, Debug)]
+pub struct IntroTime<L = ()>
+where
+    L: IntroLabel,
+{
+    source: IntroTimeSource,
+    pause_when_unfocused: bool,
+    paused: bool,
+    speed: f32,
+    // Time that is still to be swallowed by the clock before the countdowns continue
+    extension: Duration,
+    delta: Duration,
+    elapsed: Duration,
+    label: PhantomData<L>,
+}
+
+impl<L> IntroTime<L>
+where
+    L: IntroLabel,
+{
+    /// The highest speed the countdowns of the intro can run at, see [IntroTime::set_speed].
+    pub const MAX_SPEED: f32 = 1000.0;
+
+    fn new(source: IntroTimeSource, pause_when_unfocused: bool) -> Self {
+        Self {
+            source,
+            pause_when_unfocused,
+            paused: false,
+            speed: 1.0,
+            extension: Duration::ZERO,
+            delta: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            label: PhantomData,
+        }
+    }
+
+    /// Returns how much the countdowns of the intro advanced this frame.
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    /// Returns how much the countdowns of the intro advanced since [IntroPreferences::run_at]
+    /// was entered.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the clock the intro is driven by.
+    pub fn source(&self) -> IntroTimeSource {
+        self.source
+    }
+
+    /// Stops the countdowns of the intro until [IntroTime::resume] is called.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Continues the countdowns of the intro after [IntroTime::pause].
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns `true` if the intro was paused using [IntroTime::pause].
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns how fast the countdowns of the intro run, `1.0` being normal speed.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Sets how fast the countdowns of the intro run, `1.0` being normal speed.
+    ///
+    /// Negative and NaN speeds are treated as `0.0`, speeds above [IntroTime::MAX_SPEED],
+    /// including infinity, as `MAX_SPEED`.
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = match speed.is_nan() {
+            true => 0.0,
+            false => speed.clamp(0.0, Self::MAX_SPEED),
+        };
+    }
+
+    /// Extends every countdown of the intro by `duration`.
+    pub fn extend(&mut self, duration: Duration) {
+        self.extension += duration;
+    }
+}
+
+impl<S, D, U, L> IntroPreferences<S, D, U, L>
+where
+    S: States + FreelyMutableState,
+    D: IntroDuration,
+    U: ShowIntroScreen,
+    L: IntroLabel,
+{
+    pub(super) fn add_intro_time(&self, app: &mut App) {
+        app.insert_resource(IntroTime::<L>::new(
+            self.time_source,
+            self.pause_when_unfocused,
+        ))
+        .add_systems(OnEnter(self.run_at.clone()), reset_intro_time::<L>)
+        // Time itself is updated in `First`, so every countdown sees this frame's delta
+        .add_systems(PreUpdate, update_intro_time::<L>);
+    }
+}
+
+fn reset_intro_time<L: IntroLabel>(mut intro_time: ResMut<IntroTime<L>>) {
+    intro_time.paused = false;
+    intro_time.extension = Duration::ZERO;
+    intro_time.elapsed = Duration::ZERO;
+}
+
+pub(crate) fn update_intro_time<L: IntroLabel>(
+    mut intro_time: ResMut<IntroTime<L>>,
+    virtual_time: Res<Time<Virtual>>,
+    real_time: Res<Time<Real>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    let unfocused = intro_time.pause_when_unfocused
+        && windows.get_single().is_ok_and(|window| !window.focused);
+
+    let delta = match intro_time.source {
+        _ if intro_time.paused || unfocused => Duration::ZERO,
+        IntroTimeSource::Virtual => virtual_time.delta(),
+        IntroTimeSource::Real => real_time.delta(),
+    }
+    .mul_f32(intro_time.speed);
+
+    let swallowed = delta.min(intro_time.extension);
+    intro_time.extension -= swallowed;
+    let delta = delta - swallowed;
+    intro_time.delta = delta;
+    intro_time.elapsed += delta;
+}
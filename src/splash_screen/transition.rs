@@ -0,0 +1,266 @@
+use std::{marker::PhantomData, time::Duration};
+
+use bevy::{prelude::*, state::state::FreelyMutableState};
+use getset::Getters;
+
+use super::{
+    label::IntroLabel,
+    state::{is_running_labeled, LabeledState},
+    time::IntroTime,
+    IntroDuration, IntroPreferences, IntroState, ShowIntroScreen,
+};
+
+/// Describes how the intro screen is animated when it appears or disappears.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransitionKind {
+    /// Fades the content of the screen in or out.
+    Fade,
+    /// Fades the screen in from, or out to, a solid colour.
+    FadeThroughColor(Color),
+    /// Slides the screen in from, or out to, the given edge of the window.
+    Slide(SlideDirection),
+    /// Scales the screen up from, or down to, nothing.
+    Scale,
+}
+
+/// The edge of the window a [TransitionKind::Slide] moves towards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SlideDirection {
+    /// Towards the left edge of the window.
+    Left,
+    /// Towards the right edge of the window.
+    Right,
+    /// Towards the top edge of the window.
+    Up,
+    /// Towards the bottom edge of the window.
+    Down,
+}
+
+/// An enter or exit transition of the intro screen.
+#[derive(Clone, Copy, Debug, PartialEq, Getters)]
+pub struct IntroTransition {
+    /// How the screen is animated.
+    #[getset(get = "pub")]
+    kind: TransitionKind,
+    /// How long the animation takes.
+    #[getset(get = "pub")]
+    duration: Duration,
+}
+
+/// The phase the intro screen's transitions are currently in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TransitionPhase {
+    /// No intro screen is shown.
+    #[default]
+    Idle,
+    /// The enter transition is playing.
+    Entering,
+    /// The intro screen is fully shown.
+    Shown,
+    /// The exit transition is playing, the switch to [IntroPreferences::transition_to] (or to the
+    /// next slide of an [super::sequence::IntroSequence]) is held back, as is skipping the intro.
+    Exiting,
+    /// The exit transition has finished and the held back state switch has been released.
+    Exited,
+}
+
+/// Progress of the enter and exit transitions of the intro screen labelled `L`.
+#[derive(Resource, Clone, Debug, Getters)]
+pub struct TransitionProgress<L = ()>
+where
+    L: IntroLabel,
+{
+    /// The current phase.
+    #[getset(get = "pub")]
+    phase: TransitionPhase,
+    /// The transition playing in the current phase, if any.
+    #[getset(get = "pub")]
+    transition: Option<IntroTransition>,
+    timer: Timer,
+    label: PhantomData<L>,
+}
+
+// Enter and exit transitions configured by the ui backend, none until it replaces them
+#[derive(Resource, Clone, Debug)]
+pub(crate) struct IntroTransitions<L: IntroLabel = ()> {
+    enter: Option<IntroTransition>,
+    exit: Option<IntroTransition>,
+    label: PhantomData<L>,
+}
+
+// A state switch held back while the exit transition plays
+enum HeldSwitch<S: States, L: IntroLabel> {
+    State(S),
+    Intro(L::State),
+}
+
+#[derive(Resource)]
+struct PendingExit<S: States, L: IntroLabel>(Option<HeldSwitch<S, L>>);
+
+impl IntroTransition {
+    /// Creates a new `IntroTransition` of the given kind and duration.
+    pub const fn new(kind: TransitionKind, duration: Duration) -> Self {
+        Self { kind, duration }
+    }
+
+    /// Creates a new [TransitionKind::Fade] transition with the given duration.
+    pub const fn fade(duration: Duration) -> Self {
+        Self::new(TransitionKind::Fade, duration)
+    }
+}
+
+impl<L> Default for TransitionProgress<L>
+where
+    L: IntroLabel,
+{
+    fn default() -> Self {
+        Self {
+            phase: TransitionPhase::default(),
+            transition: None,
+            timer: Timer::default(),
+            label: PhantomData,
+        }
+    }
+}
+
+impl<L> TransitionProgress<L>
+where
+    L: IntroLabel,
+{
+    /// Returns how visible the intro screen currently is, from `0.0` (hidden) to `1.0` (fully shown).
+    pub fn visibility(&self) -> f32 {
+        match self.phase {
+            TransitionPhase::Entering => self.timer.fraction(),
+            TransitionPhase::Exiting => self.timer.fraction_remaining(),
+            TransitionPhase::Exited => 0.0,
+            TransitionPhase::Idle | TransitionPhase::Shown => 1.0,
+        }
+    }
+
+    fn start(&mut self, phase: TransitionPhase, transition: Option<IntroTransition>) {
+        let duration = transition.map(|t| t.duration).unwrap_or_default();
+
+        self.phase = phase;
+        self.transition = transition;
+        self.timer = Timer::new(duration, TimerMode::Once);
+    }
+}
+
+impl<L> IntroTransitions<L>
+where
+    L: IntroLabel,
+{
+    pub(crate) const fn new(enter: Option<IntroTransition>, exit: Option<IntroTransition>) -> Self {
+        Self {
+            enter,
+            exit,
+            label: PhantomData,
+        }
+    }
+}
+
+impl<S, D, U, L> IntroPreferences<S, D, U, L>
+where
+    S: States + FreelyMutableState,
+    D: IntroDuration,
+    U: ShowIntroScreen,
+    L: IntroLabel,
+{
+    // The ui backends only replace the `IntroTransitions`, so they don't need to switch states
+    pub(super) fn add_transitions(&self, app: &mut App) {
+        // The durations switch to `transition_to` while skipping and the slides of a sequence
+        // switch the intro state, so these switches are intercepted after `Update` and only
+        // released once the exit transition has finished
+        app.insert_resource(IntroTransitions::<L>::new(None, None))
+            .init_resource::<TransitionProgress<L>>()
+            .insert_resource(PendingExit::<S, L>(None))
+            .add_systems(OnEnter(L::State::from(IntroState::Running)), start_enter_transition::<L>)
+            .add_systems(OnExit(L::State::from(IntroState::Running)), reset_transition::<L>)
+            .add_systems(Update, tick_transition::<S, L>.run_if(is_running_labeled::<L>))
+            .add_systems(PostUpdate, hold_exit::<S, L>.run_if(is_running_labeled::<L>));
+    }
+}
+
+fn start_enter_transition<L: IntroLabel>(
+    mut progress: ResMut<TransitionProgress<L>>,
+    transitions: Res<IntroTransitions<L>>,
+) {
+    match transitions.enter {
+        Some(enter) => progress.start(TransitionPhase::Entering, Some(enter)),
+        None => progress.start(TransitionPhase::Shown, None),
+    }
+}
+
+fn reset_transition<L: IntroLabel>(mut progress: ResMut<TransitionProgress<L>>) {
+    progress.start(TransitionPhase::Idle, None);
+}
+
+fn tick_transition<S, L>(
+    mut progress: ResMut<TransitionProgress<L>>,
+    mut next_state: ResMut<NextState<S>>,
+    mut next_intro_state: ResMut<NextState<L::State>>,
+    mut pending: ResMut<PendingExit<S, L>>,
+    time: Res<IntroTime<L>>,
+) where
+    S: States + FreelyMutableState,
+    L: IntroLabel,
+{
+    if !progress.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    match progress.phase {
+        TransitionPhase::Entering => progress.start(TransitionPhase::Shown, None),
+        TransitionPhase::Exiting => {
+            progress.phase = TransitionPhase::Exited;
+            match pending.0.take() {
+                Some(HeldSwitch::State(target)) => next_state.set(target),
+                Some(HeldSwitch::Intro(target)) => next_intro_state.set(target),
+                None => {}
+            }
+        }
+        _ => {}
+    }
+}
+
+fn hold_exit<S, L>(
+    mut next_state: ResMut<NextState<S>>,
+    mut next_intro_state: ResMut<NextState<L::State>>,
+    mut pending: ResMut<PendingExit<S, L>>,
+    mut progress: ResMut<TransitionProgress<L>>,
+    transitions: Res<IntroTransitions<L>>,
+) where
+    S: States + FreelyMutableState,
+    L: IntroLabel,
+{
+    if transitions.exit.is_none() || progress.phase == TransitionPhase::Exited {
+        return;
+    }
+
+    // Skipping goes through `Skipped` and a slide of a sequence ends by going back to loading
+    // the next one, failures aren't held back
+    let held = if let NextState::Pending(target) = &*next_state {
+        let held = HeldSwitch::State(target.clone());
+        next_state.reset();
+        held
+    } else if let NextState::Pending(target) = &*next_intro_state {
+        if !matches!(
+            target.unlabeled(),
+            IntroState::Loading | IntroState::Skipped
+        ) {
+            return;
+        }
+
+        let held = HeldSwitch::Intro(*target);
+        next_intro_state.reset();
+        held
+    } else {
+        return;
+    };
+
+    pending.0 = Some(held);
+
+    if progress.phase != TransitionPhase::Exiting {
+        progress.start(TransitionPhase::Exiting, transitions.exit);
+    }
+}
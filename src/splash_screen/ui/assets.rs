@@ -1,7 +1,11 @@
 use std::marker::PhantomData;
+use bevy::{asset::UntypedAssetLoadFailedEvent, prelude::*};
 use bevy_asset_loader::prelude::*;
 
-use crate::splash_screen::IntroState;
+use crate::splash_screen::{
+    reason::{IntroFailure, IntroFailureReason},
+    IntroLabel, IntroState,
+};
 
 use super::ShowIntroScreen;
 
@@ -28,21 +32,40 @@ impl<T> ShowIntroScreen for IntroAssetLoader<T>
 where
     T: AssetCollection,
 {
-    fn configure_ui<S, D, U>(
+    fn configure_ui<S, D, U, L>(
         &self,
         app: &mut bevy::prelude::App,
-        _: &crate::splash_screen::IntroPreferences<S, D, U>,
+        _: &crate::splash_screen::IntroPreferences<S, D, U, L>,
     ) where
         S: bevy::prelude::States,
         D: crate::splash_screen::IntroDuration,
         U: ShowIntroScreen,
+        L: IntroLabel,
     {
         app.add_loading_state(
-            LoadingState::new(IntroState::Loading)
-                .continue_to_state(IntroState::Running)
-                .on_failure_continue_to_state(IntroState::Failure)
+            LoadingState::new(L::State::from(IntroState::Loading))
+                .continue_to_state(L::State::from(IntroState::Running))
+                .on_failure_continue_to_state(L::State::from(IntroState::Failure))
                 .load_collection::<T>(),
+        )
+        .add_systems(
+            Update,
+            report_failed_asset::<L>.run_if(in_state(L::State::from(IntroState::Loading))),
         );
     }
 }
 
+// The loading state doesn't say which asset failed, so the failures are picked up from the
+// asset server while the intro is loading
+fn report_failed_asset<L: IntroLabel>(
+    mut failures: EventReader<UntypedAssetLoadFailedEvent>,
+    mut failure: ResMut<IntroFailure<L>>,
+) {
+    for event in failures.read() {
+        failure.report(IntroFailureReason::AssetLoad {
+            path: Some(event.path.path().display().to_string()),
+            error: event.error.to_string(),
+        });
+    }
+}
+
@@ -0,0 +1,290 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bevy::{prelude::*, state::state::FreelyMutableState};
+
+use super::{IntroFailureManager, IntroFailureSchedules, ShowIntroScreen};
+use crate::splash_screen::{
+    reason::{DismissIntroFailure, IntroFailure, IntroFailureReason},
+    time::IntroTime,
+    IntroDuration, IntroLabel,
+};
+
+/// Runs the first manager that handles the failure, see [IntroFailureManager::handles].
+///
+/// This is typically created using [IntroFailureManager::or_else].
+#[derive(Clone)]
+pub struct OrElse<A, B>
+where
+    A: IntroFailureManager,
+    B: IntroFailureManager,
+{
+    first: A,
+    second: B,
+}
+
+/// Runs the second manager once the failure handled by the first one is dismissed using
+/// [DismissIntroFailure].
+///
+/// This is typically created using [IntroFailureManager::then].
+#[derive(Clone)]
+pub struct Then<A, B>
+where
+    A: IntroFailureManager,
+    B: IntroFailureManager,
+{
+    first: A,
+    second: B,
+}
+
+/// Only runs the manager for failures whose reason matches the predicate.
+///
+/// This is typically created using [IntroFailureManager::when].
+#[derive(Clone)]
+pub struct When<M, P>
+where
+    M: IntroFailureManager,
+    P: Fn(Option<&IntroFailureReason>) -> bool + Send + Sync + 'static,
+{
+    manager: M,
+    predicate: P,
+}
+
+/// Waits for a delay after the failure before running the manager.
+///
+/// Every `OnFailureWithDelay` has a timer of its own, which starts over whenever the failure
+/// reaches it and is driven by the intro's [IntroTime]. This is typically created using [IntroFailureManager::delayed].
+#[derive(Clone)]
+pub struct OnFailureWithDelay<M>
+where
+    M: IntroFailureManager,
+{
+    manager: M,
+    delay: Duration,
+}
+
+impl<A, B> OrElse<A, B>
+where
+    A: IntroFailureManager,
+    B: IntroFailureManager,
+{
+    /// Creates a new `OrElse`, running `second` only if `first` doesn't handle the failure.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<A, B> Then<A, B>
+where
+    A: IntroFailureManager,
+    B: IntroFailureManager,
+{
+    /// Creates a new `Then`, running `second` once the failure handled by `first` is dismissed.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<M, P> When<M, P>
+where
+    M: IntroFailureManager,
+    P: Fn(Option<&IntroFailureReason>) -> bool + Send + Sync + 'static,
+{
+    /// Creates a new `When`, only running `manager` for failures whose reason matches `predicate`.
+    pub fn new(manager: M, predicate: P) -> Self {
+        Self { manager, predicate }
+    }
+}
+
+impl<M> OnFailureWithDelay<M>
+where
+    M: IntroFailureManager,
+{
+    /// Creates a new `OnFailureWithDelay`, running `manager` once `delay` has passed.
+    pub fn new(manager: M, delay: Duration) -> Self {
+        Self { manager, delay }
+    }
+
+    /// Returns how long the failure waits before the manager runs.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+}
+
+impl<A, B> IntroFailureManager for OrElse<A, B>
+where
+    A: IntroFailureManager + Clone,
+    B: IntroFailureManager + Clone,
+{
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
+    where
+        S: States + FreelyMutableState,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        let first = schedules.branch(app);
+        let second = schedules.branch(app);
+        self.first
+            .manage_failure_in::<S, D, U, L>(app, first.clone());
+        self.second
+            .manage_failure_in::<S, D, U, L>(app, second.clone());
+
+        let managers = self.clone();
+        app.add_systems(schedules.enter(), move |world: &mut World| {
+            let reason = failure_reason::<L>(world);
+            if managers.first.handles(reason.as_ref()) {
+                first.activate(world);
+            } else if managers.second.handles(reason.as_ref()) {
+                second.activate(world);
+            }
+        });
+    }
+
+    fn handles(&self, reason: Option<&IntroFailureReason>) -> bool {
+        self.first.handles(reason) || self.second.handles(reason)
+    }
+}
+
+impl<A, B> IntroFailureManager for Then<A, B>
+where
+    A: IntroFailureManager + Clone,
+    B: IntroFailureManager + Clone,
+{
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
+    where
+        S: States + FreelyMutableState,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        let first = schedules.branch(app);
+        let second = schedules.branch(app);
+        self.first
+            .manage_failure_in::<S, D, U, L>(app, first.clone());
+        self.second
+            .manage_failure_in::<S, D, U, L>(app, second.clone());
+
+        // Dismissals are handled outside of the branches, so nested steps still see them
+        if !app.world().contains_resource::<ThenSteps<L>>() {
+            app.insert_resource(ThenSteps::<L>(Vec::new()))
+                .add_systems(PreUpdate, dismiss_failure::<L>);
+        }
+        app.world_mut()
+            .resource_mut::<ThenSteps<L>>()
+            .0
+            .push((first.clone(), second.clone()));
+
+        let first_manager = self.first.clone();
+        app.add_systems(schedules.enter(), move |world: &mut World| {
+            let reason = failure_reason::<L>(world);
+            match first_manager.handles(reason.as_ref()) {
+                true => first.activate(world),
+                false => second.activate(world),
+            }
+        });
+    }
+
+    fn handles(&self, reason: Option<&IntroFailureReason>) -> bool {
+        self.first.handles(reason) || self.second.handles(reason)
+    }
+}
+
+impl<M, P> IntroFailureManager for When<M, P>
+where
+    M: IntroFailureManager + Clone,
+    P: Fn(Option<&IntroFailureReason>) -> bool + Clone + Send + Sync + 'static,
+{
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
+    where
+        S: States + FreelyMutableState,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        let branch = schedules.branch(app);
+        self.manager
+            .manage_failure_in::<S, D, U, L>(app, branch.clone());
+
+        let when = self.clone();
+        app.add_systems(schedules.enter(), move |world: &mut World| {
+            if when.handles(failure_reason::<L>(world).as_ref()) {
+                branch.activate(world);
+            }
+        });
+    }
+
+    fn handles(&self, reason: Option<&IntroFailureReason>) -> bool {
+        (self.predicate)(reason) && self.manager.handles(reason)
+    }
+}
+
+impl<M> IntroFailureManager for OnFailureWithDelay<M>
+where
+    M: IntroFailureManager,
+{
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
+    where
+        S: States + FreelyMutableState,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        let branch = schedules.branch(app);
+        self.manager
+            .manage_failure_in::<S, D, U, L>(app, branch.clone());
+
+        // The timer belongs to this instance, so several delays can be used for the same intro
+        let timer = Arc::new(Mutex::new(Timer::new(self.delay, TimerMode::Once)));
+        let countdown = timer.clone();
+
+        app.add_systems(schedules.enter(), move || timer.lock().unwrap().reset())
+            .add_systems(
+                Update,
+                (move |mut commands: Commands, time: Res<IntroTime<L>>| {
+                    if countdown.lock().unwrap().tick(time.delta()).just_finished() {
+                        let branch = branch.clone();
+                        commands.add(move |world: &mut World| branch.activate(world));
+                    }
+                })
+                .in_set(schedules.set()),
+            );
+    }
+
+    fn handles(&self, reason: Option<&IntroFailureReason>) -> bool {
+        self.manager.handles(reason)
+    }
+}
+
+fn failure_reason<L: IntroLabel>(world: &World) -> Option<IntroFailureReason> {
+    world.resource::<IntroFailure<L>>().reason().cloned()
+}
+
+// The steps of every `Then` of the intro labelled `L`
+#[derive(Resource)]
+struct ThenSteps<L: IntroLabel>(Vec<(IntroFailureSchedules<L>, IntroFailureSchedules<L>)>);
+
+fn dismiss_failure<L: IntroLabel>(
+    mut events: EventReader<DismissIntroFailure<L>>,
+    mut commands: Commands,
+) {
+    if events.read().count() > 0 {
+        commands.add(advance_then::<L>);
+    }
+}
+
+// The most recently picked step is the innermost one, which is the one being dismissed
+fn advance_then<L: IntroLabel>(world: &mut World) {
+    let steps = world.resource::<ThenSteps<L>>().0.clone();
+    let current = steps
+        .iter()
+        .filter_map(|(first, second)| Some((first.activated_at(world)?, first, second)))
+        .max_by_key(|(activated_at, ..)| *activated_at);
+
+    if let Some((_, first, second)) = current {
+        first.deactivate(world);
+        second.activate(world);
+    }
+}
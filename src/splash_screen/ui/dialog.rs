@@ -0,0 +1,153 @@
+// The dialog is only shown by the egui and bevy_ui backends
+#![cfg_attr(not(any(feature = "egui", feature = "bevy_ui")), allow(dead_code))]
+
+use std::{borrow::Cow, marker::PhantomData};
+
+use bevy::{prelude::*, state::state::FreelyMutableState};
+use getset::Getters;
+
+use super::{IntroFailureSchedules, ShowIntroScreen};
+use crate::splash_screen::{
+    reason::DismissIntroFailure, restart::IntroRestart, IntroDuration, IntroLabel,
+    IntroPreferences, IntroResource,
+};
+
+/// The contents of a failure dialog, shown by the `OnFailureShowDialog` manager of the egui and
+/// bevy_ui backends.
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+#[bon::builder]
+pub struct FailureDialog {
+    /// The title of the dialog.
+    #[builder(into, default = Cow::Borrowed("Something went wrong"))]
+    title: Cow<'static, str>,
+    /// The message shown below the title.
+    #[builder(into)]
+    message: Option<Cow<'static, str>>,
+    /// Whether the [crate::prelude::IntroFailure::reason] is shown below the message, if it is known.
+    #[builder(default = true)]
+    show_reason: bool,
+    /// The buttons of the dialog, in the order they are shown.
+    #[builder(default = vec![FailureDialogButton::Retry, FailureDialogButton::Continue])]
+    buttons: Vec<FailureDialogButton>,
+}
+
+impl Default for FailureDialog {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// A button of a [FailureDialog].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FailureDialogButton {
+    /// Loads the intro screen again, starting its duration over like [super::OnFailureRetry].
+    Retry,
+    /// Continues to [IntroPreferences::transition_to].
+    Continue,
+    /// Closes the app.
+    Quit,
+    /// Closes the dialog and sends [DismissIntroFailure], so a [super::Then] moves on to its next
+    /// manager.
+    Dismiss,
+}
+
+impl FailureDialogButton {
+    /// Returns the text shown on the button.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Retry => "Retry",
+            Self::Continue => "Continue",
+            Self::Quit => "Quit",
+            Self::Dismiss => "Dismiss",
+        }
+    }
+}
+
+/// Sent when a button of the failure dialog of the intro labelled `L` is pressed.
+#[derive(Event, Clone, Debug)]
+pub struct FailureDialogChoice<L = ()>
+where
+    L: IntroLabel,
+{
+    /// The button that was pressed.
+    pub button: FailureDialogButton,
+    label: PhantomData<L>,
+}
+
+impl<L> FailureDialogChoice<L>
+where
+    L: IntroLabel,
+{
+    /// Chooses `button`, as if it was pressed, while the dialog is shown.
+    pub fn new(button: FailureDialogButton) -> Self {
+        Self {
+            button,
+            label: PhantomData,
+        }
+    }
+}
+
+// Whether the failure dialog of the intro labelled `L` is shown, it is closed by any choice
+#[derive(Resource)]
+pub(crate) struct FailureDialogOpen<L: IntroLabel>(pub(crate) bool, PhantomData<L>);
+
+impl FailureDialog {
+    // The backends only show the dialog, the choices are handled the same way for both of them
+    pub(crate) fn configure<S, D, U, L>(&self, app: &mut App, schedules: &IntroFailureSchedules<L>)
+    where
+        S: States + FreelyMutableState,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        app.insert_resource(IntroResource::<_, L>::new(self.clone()))
+            .insert_resource(FailureDialogOpen::<L>(false, PhantomData))
+            .add_event::<FailureDialogChoice<L>>()
+            .add_systems(schedules.enter(), open_dialog::<L>)
+            .add_systems(Update, handle_choice::<S, D, U, L>.in_set(schedules.set()))
+            .add_systems(schedules.exit(), close_dialog::<L>);
+    }
+}
+
+fn open_dialog<L: IntroLabel>(mut open: ResMut<FailureDialogOpen<L>>) {
+    open.0 = true;
+}
+
+fn close_dialog<L: IntroLabel>(mut open: ResMut<FailureDialogOpen<L>>) {
+    open.0 = false;
+}
+
+fn handle_choice<S, D, U, L>(
+    mut choices: EventReader<FailureDialogChoice<L>>,
+    mut open: ResMut<FailureDialogOpen<L>>,
+    mut restart: IntroRestart<L>,
+    mut next_state: ResMut<NextState<S>>,
+    mut dismissals: EventWriter<DismissIntroFailure<L>>,
+    mut exit: EventWriter<AppExit>,
+    preferences: Res<IntroPreferences<S, D, U, L>>,
+) where
+    S: States + FreelyMutableState,
+    D: IntroDuration,
+    U: ShowIntroScreen,
+    L: IntroLabel,
+{
+    // Only the first choice counts, as the dialog is closed right away
+    let choice = choices.read().next().map(|choice| choice.button);
+    choices.clear();
+    let Some(button) = choice.filter(|_| open.0) else {
+        return;
+    };
+
+    open.0 = false;
+    match button {
+        FailureDialogButton::Retry => restart.restart(),
+        FailureDialogButton::Continue => next_state.set(preferences.transition_to.clone()),
+        FailureDialogButton::Quit => {
+            exit.send(AppExit::Success);
+        }
+        FailureDialogButton::Dismiss => {
+            dismissals.send(DismissIntroFailure::default());
+        }
+    }
+}
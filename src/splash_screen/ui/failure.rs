@@ -1,5 +1,12 @@
-use super::ShowIntroScreen;
-use crate::splash_screen::{IntroDuration, IntroPreferences, IntroState};
+use super::{
+    combinator::{OnFailureWithDelay, OrElse, Then, When},
+    schedules::IntroFailureSchedules,
+    ShowIntroScreen,
+};
+use crate::splash_screen::{
+    reason::{IntroFailure, IntroFailureReason},
+    IntroDuration, IntroLabel, IntroPreferences, IntroState,
+};
 use bevy::{prelude::*, state::state::FreelyMutableState};
 use std::{marker::PhantomData, time::Duration};
 
@@ -10,11 +17,40 @@ pub trait IntroFailureManager: Send + Sync + 'static {
     /// This method is called when the splash screen enters the `Failure` state.
     /// The provided `schedule` should be used to schedule systems or events
     /// to handle the failure
+    ///
+    /// Only used for intros without a label, this calls
+    /// [IntroFailureManager::manage_failure_in] with the schedules of the `Failure` state.
     fn manage_failure<S, D, U>(&self, app: &mut App, schedule: OnEnter<IntroState>)
     where
         S: States + FreelyMutableState,
         D: IntroDuration,
-        U: ShowIntroScreen;
+        U: ShowIntroScreen,
+    {
+        let _ = schedule;
+        self.manage_failure_in::<S, D, U, ()>(app, IntroFailureSchedules::new())
+    }
+
+    /// Manages failures, adding the systems to `schedules`.
+    ///
+    /// Combinators like [OrElse] give every manager they pick from schedules of its own, which
+    /// only run once the manager is picked, and a [IntroFailureSchedules::set] for the systems
+    /// that should only run while it manages the failure. Systems added to any other schedule
+    /// should be put in that set, or they run whether the manager was picked or not.
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
+    where
+        S: States + FreelyMutableState,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel;
+
+    /// Returns `true` if the manager takes care of a failure because of `reason`.
+    ///
+    /// Used by [OrElse], [Then] and [When] to pick the managers that run, every manager handles
+    /// every failure unless it is conditional like [When].
+    fn handles(&self, reason: Option<&IntroFailureReason>) -> bool {
+        let _ = reason;
+        true
+    }
 
     /// Combines two `IntroFailureManager` instances into a single one.
     ///
@@ -30,6 +66,42 @@ pub trait IntroFailureManager: Send + Sync + 'static {
             second: other,
         }
     }
+
+    /// Runs `other` only if this manager doesn't handle the failure.
+    fn or_else<B>(self, other: B) -> OrElse<Self, B>
+    where
+        B: IntroFailureManager,
+        Self: Sized,
+    {
+        OrElse::new(self, other)
+    }
+
+    /// Runs `other` once the failure handled by this manager is dismissed using
+    /// [crate::prelude::DismissIntroFailure], or right away if this manager doesn't handle it.
+    fn then<B>(self, other: B) -> Then<Self, B>
+    where
+        B: IntroFailureManager,
+        Self: Sized,
+    {
+        Then::new(self, other)
+    }
+
+    /// Waits for `delay` after the failure before running this manager.
+    fn delayed(self, delay: Duration) -> OnFailureWithDelay<Self>
+    where
+        Self: Sized,
+    {
+        OnFailureWithDelay::new(self, delay)
+    }
+
+    /// Only handles failures whose reason matches `predicate`.
+    fn when<P>(self, predicate: P) -> When<Self, P>
+    where
+        P: Fn(Option<&IntroFailureReason>) -> bool + Send + Sync + 'static,
+        Self: Sized,
+    {
+        When::new(self, predicate)
+    }
 }
 
 /// Creates a new `And` instance combining two `IntroFailureManager` instances.
@@ -53,24 +125,50 @@ pub struct OnFailureCloseWindow;
 #[derive(Clone)]
 pub struct OnFailureContinue;
 
+/// Sends the game to another state than [IntroPreferences::transition_to] when a splash screen
+/// failure occurs, like an error screen or a safe mode.
+///
+/// The intro goes back to [IntroState::Idle], so it plays again once
+/// [IntroPreferences::run_at] is entered anew. The reason of the failure is kept in
+/// [IntroFailureDetails] until the state the game was sent to is left.
+#[derive(Clone)]
+pub struct OnFailureGoTo<T>(pub T)
+where
+    T: States + FreelyMutableState;
+
+/// Why the intro labelled `L` failed, stored by [OnFailureGoTo] for the state it sends the game
+/// to.
+#[derive(Resource, Clone, Debug)]
+pub struct IntroFailureDetails<L = ()>
+where
+    L: IntroLabel,
+{
+    reason: Option<IntroFailureReason>,
+    label: PhantomData<L>,
+}
+
+impl<L> IntroFailureDetails<L>
+where
+    L: IntroLabel,
+{
+    /// Returns why the intro failed, if the reason is known.
+    pub fn reason(&self) -> Option<&IntroFailureReason> {
+        self.reason.as_ref()
+    }
+}
+
 /// Closes the window **after a delay** when a splash screen failure occurs.
+///
+/// Same as `OnFailureCloseWindow.delayed(delay)`, see [OnFailureWithDelay].
 #[derive(Clone)]
 pub struct OnFailureCloseWindowWithDelay(pub Duration);
 
 /// Continues to the next state (aka [IntroPreferences::transition_to]) **after a delay** when a splash screen failure occurs.
+///
+/// Same as `OnFailureContinue.delayed(delay)`, see [OnFailureWithDelay].
 #[derive(Clone)]
 pub struct OnFailureContinueWithDelay(pub Duration);
 
-// used to create the `*WithDelay`` Structs and so that they have their own timer
-#[derive(Resource, Clone)]
-struct WithDelay<T>
-where
-    T: Resource,
-{
-    timer: Timer,
-    owner: PhantomData<T>,
-}
-
 impl OnFailureCloseWindow {
     /// Internal system of [OnFailureCloseWindow]
     pub fn system(mut exit: EventWriter<AppExit>) {
@@ -80,145 +178,123 @@ impl OnFailureCloseWindow {
 
 impl OnFailureContinue {
     /// Internal system of [OnFailureContinue]
-    pub fn system<S: States + FreelyMutableState, D: IntroDuration, U: ShowIntroScreen>(
+    pub fn system<S, D, U, L>(
         mut next_state: ResMut<NextState<S>>,
-        conf: Res<IntroPreferences<S, D, U>>,
-    ) {
+        conf: Res<IntroPreferences<S, D, U, L>>,
+    ) where
+        S: States + FreelyMutableState,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
         next_state.set(conf.transition_to.clone())
     }
 }
 
-impl<T> WithDelay<T>
-where
-    T: Resource,
-{
-    fn new(duration: Duration) -> Self {
-        Self {
-            timer: Timer::new(duration, TimerMode::Once),
-            owner: PhantomData::<T>,
-        }
-    }
-
-    pub fn system(mut countdown: ResMut<Self>, time: Res<Time>) {
-        countdown.timer.tick(time.delta());
-    }
-
-    pub fn delay_finished(countdown: Res<Self>) -> bool {
-        countdown.timer.finished()
-    }
-}
-
 impl<A, B> IntroFailureManager for And<A, B>
 where
     A: IntroFailureManager,
     B: IntroFailureManager,
 {
-    fn manage_failure<S, D, U>(&self, app: &mut App, schedule: OnEnter<IntroState>)
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
     where
         S: States + FreelyMutableState,
         D: IntroDuration,
         U: ShowIntroScreen,
+        L: IntroLabel,
     {
-        self.first.manage_failure::<S, D, U>(app, schedule.clone());
-        self.second.manage_failure::<S, D, U>(app, schedule);
+        self.first
+            .manage_failure_in::<S, D, U, L>(app, schedules.clone());
+        self.second.manage_failure_in::<S, D, U, L>(app, schedules);
+    }
+
+    fn handles(&self, reason: Option<&IntroFailureReason>) -> bool {
+        self.first.handles(reason) || self.second.handles(reason)
     }
 }
 
 impl IntroFailureManager for OnFailureCloseWindow {
-    fn manage_failure<S, D, U>(&self, app: &mut App, schedule: OnEnter<IntroState>)
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
     where
         S: States + FreelyMutableState,
         D: IntroDuration,
         U: ShowIntroScreen,
+        L: IntroLabel,
     {
-        app.add_systems(schedule, Self::system);
+        app.add_systems(schedules.enter(), Self::system);
     }
 }
 
 impl IntroFailureManager for OnFailureContinue {
-    fn manage_failure<S, D, U>(&self, app: &mut App, schedule: OnEnter<IntroState>)
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
     where
         S: States + FreelyMutableState,
         D: IntroDuration,
         U: ShowIntroScreen,
+        L: IntroLabel,
     {
-        app.add_systems(schedule, Self::system::<S, D, U>);
+        app.add_systems(schedules.enter(), Self::system::<S, D, U, L>);
     }
 }
 
-impl<T> IntroFailureManager for WithDelay<T>
-where
-    T: Resource + Clone,
-{
-    fn manage_failure<S, D, U>(&self, app: &mut App, schedule: OnEnter<IntroState>)
+impl IntroFailureManager for OnFailureCloseWindowWithDelay {
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
     where
         S: States + FreelyMutableState,
         D: IntroDuration,
         U: ShowIntroScreen,
+        L: IntroLabel,
     {
-        app.insert_resource(self.clone())
-            .add_systems(Update, Self::system.run_if(in_state(schedule.0)));
+        OnFailureCloseWindow
+            .delayed(self.0)
+            .manage_failure_in::<S, D, U, L>(app, schedules);
     }
 }
 
-// General Macro to create *With Delay
-/*
-macro_rules! with_delay_impl {
-    ($name : ty,$marker : ident,$inner :ty) => {
-        #[derive(Resource,Clone)]
-        struct $marker;
-
-        impl IntroFailureManager for $name {
-    fn manage_failure<S, D, U>(&self, app: &mut App, schedule: OnEnter<IntroState>)
+impl IntroFailureManager for OnFailureContinueWithDelay {
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
     where
         S: States + FreelyMutableState,
         D: IntroDuration,
         U: ShowIntroScreen,
+        L: IntroLabel,
     {
-        let delay = WithDelay::<$marker>::new(self.0.clone());
-        delay.manage_failure::<S,D,U>(app,schedule.clone());
-
-        app.insert_resource(delay).add_systems(schedule,<$inner>::system.run_if(WithDelay::<$marker>::delay_finished));
+        OnFailureContinue
+            .delayed(self.0)
+            .manage_failure_in::<S, D, U, L>(app, schedules);
     }
 }
-    }
-}*/
-
-#[derive(Resource, Clone)]
-struct CloseWindowDelayMaker;
 
-impl IntroFailureManager for OnFailureCloseWindowWithDelay {
-    fn manage_failure<S, D, U>(&self, app: &mut App, schedule: OnEnter<IntroState>)
+impl<T> IntroFailureManager for OnFailureGoTo<T>
+where
+    T: States + FreelyMutableState,
+{
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
     where
         S: States + FreelyMutableState,
         D: IntroDuration,
         U: ShowIntroScreen,
+        L: IntroLabel,
     {
-        let delay = WithDelay::<CloseWindowDelayMaker>::new(self.0.clone());
-        delay.manage_failure::<S, D, U>(app, schedule.clone());
-        app.insert_resource(delay).add_systems(
-            schedule,
-            <OnFailureCloseWindow>::system
-                .run_if(WithDelay::<CloseWindowDelayMaker>::delay_finished),
-        );
+        let state = self.0.clone();
+        app.add_systems(
+            schedules.enter(),
+            move |mut commands: Commands,
+                  mut next_state: ResMut<NextState<T>>,
+                  mut next_intro_state: ResMut<NextState<L::State>>,
+                  failure: Res<IntroFailure<L>>| {
+                commands.insert_resource(IntroFailureDetails::<L> {
+                    reason: failure.reason().cloned(),
+                    label: PhantomData,
+                });
+                next_state.set(state.clone());
+                next_intro_state.set(IntroState::Idle.into());
+            },
+        )
+        .add_systems(OnExit(self.0.clone()), remove_failure_details::<L>);
     }
 }
-#[derive(Resource, Clone)]
-struct ContinueDelayMaker;
 
-impl IntroFailureManager for OnFailureContinueWithDelay {
-    fn manage_failure<S, D, U>(&self, app: &mut App, schedule: OnEnter<IntroState>)
-    where
-        S: States + FreelyMutableState,
-        D: IntroDuration,
-        U: ShowIntroScreen,
-    {
-        let delay = WithDelay::<ContinueDelayMaker>::new(self.0.clone());
-        delay.manage_failure::<S, D, U>(app, schedule.clone());
-        app.insert_resource(delay).add_systems(
-            schedule,
-            <OnFailureContinue>::system::<S, D, U>
-                .run_if(WithDelay::<ContinueDelayMaker>::delay_finished),
-        );
-    }
+fn remove_failure_details<L: IntroLabel>(mut commands: Commands) {
+    commands.remove_resource::<IntroFailureDetails<L>>();
 }
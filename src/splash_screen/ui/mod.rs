@@ -1,23 +1,45 @@
 #[cfg(feature = "assets")]
 mod assets;
+mod combinator;
+mod dialog;
 mod failure;
+mod retry;
+mod schedules;
 
 #[cfg(feature = "assets")]
 pub use assets::*;
+pub use combinator::*;
+pub use dialog::*;
 pub use failure::*;
+pub use retry::*;
+pub use schedules::*;
 
-use super::{IntroDuration, IntroPreferences};
+use super::{label::IntroLabel, IntroDuration, IntroPreferences};
 use bevy::prelude::*;
+#[cfg(all(feature = "assets", feature = "bevy_ui"))]
 use bevy_asset_loader::prelude::AssetCollection;
 
 /// This trait provides a generic interface for defining custom splash screen content.
 pub trait ShowIntroScreen: Send + Sync + 'static {
     /// Configures the splash screen UI.
-    fn configure_ui<S, D, U>(&self, app: &mut App, preferences: &IntroPreferences<S, D, U>)
+    fn configure_ui<S, D, U, L>(&self, app: &mut App, preferences: &IntroPreferences<S, D, U, L>)
     where
         S: States,
         D: IntroDuration,
-        U: ShowIntroScreen;
+        U: ShowIntroScreen,
+        L: IntroLabel;
+
+    /// Applies the parts of the configuration that differ between instances, like transitions,
+    /// once this screen becomes the active slide of an [IntroSequence](crate::prelude::IntroSequence).
+    ///
+    /// Only the first slide is configured using [ShowIntroScreen::configure_ui], so anything it
+    /// reads from `self` has to be refreshed here. Does nothing by default.
+    fn configure_slide<L>(&self, commands: &mut Commands)
+    where
+        L: IntroLabel,
+    {
+        let _ = commands;
+    }
 }
 
 #[cfg(all(feature= "assets",any(feature="bevy_ui")))]
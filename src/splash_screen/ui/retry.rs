@@ -0,0 +1,199 @@
+use std::{marker::PhantomData, time::Duration};
+
+use bevy::{prelude::*, state::state::FreelyMutableState};
+
+use super::{IntroFailureManager, IntroFailureSchedules, ShowIntroScreen};
+use crate::splash_screen::{
+    progress::IntroProgress,
+    reason::IntroFailureReason,
+    restart::IntroRestart,
+    state::LabeledState,
+    time::{update_intro_time, IntroTime},
+    IntroDuration, IntroLabel, IntroPreferences, IntroState,
+};
+
+/// Loads the intro screen again when a failure occurs, falling back to another
+/// [IntroFailureManager] once every attempt has failed.
+///
+/// Retrying is meant for failures while loading the assets of the intro screen, like those
+/// reported by [super::IntroAssetLoader], or of a dynamic duration. Every attempt starts the
+/// intro over, see [crate::prelude::OnIntroStart]. Before every attempt the manager waits for a
+/// backoff delay, which doubles with every attempt and is counted down with the [IntroTime] of
+/// the intro, and shows the attempt count as the message of the [IntroProgress]. The state of
+/// the retries is available through [IntroRetries].
+///
+/// Retries happen before any other manager sees the failure, so `OnFailureRetry` is meant to
+/// wrap the other managers rather than be combined into them using [super::Then] or [super::OrElse].
+#[derive(Clone)]
+pub struct OnFailureRetry<F>
+where
+    F: IntroFailureManager,
+{
+    attempts: u32,
+    backoff: Duration,
+    fallback: F,
+}
+
+/// The retries of the [OnFailureRetry] manager of the intro labelled `L`.
+///
+/// The attempts are counted from the start of every run of the intro.
+#[derive(Resource, Clone, Debug)]
+pub struct IntroRetries<L = ()>
+where
+    L: IntroLabel,
+{
+    attempt: u32,
+    max_attempts: u32,
+    backoff: Duration,
+    delay: Option<Timer>,
+    label: PhantomData<L>,
+}
+
+impl<F> OnFailureRetry<F>
+where
+    F: IntroFailureManager,
+{
+    /// Retries loading the intro screen up to `attempts` times before handing the failure to
+    /// `fallback`.
+    pub fn new(attempts: u32, fallback: F) -> Self {
+        Self {
+            attempts,
+            backoff: Duration::from_millis(500),
+            fallback,
+        }
+    }
+
+    /// Sets the delay before the first retry, defaults to half a second.
+    pub fn with_backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+}
+
+impl<L> IntroRetries<L>
+where
+    L: IntroLabel,
+{
+    /// Returns the current attempt, `0` before the first retry.
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Returns how many times loading the intro screen is retried at most.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns `true` while waiting for the backoff delay before the next attempt.
+    pub fn is_waiting(&self) -> bool {
+        self.delay.is_some()
+    }
+
+    fn retry_message(&self) -> String {
+        format!("Retrying ({}/{})", self.attempt, self.max_attempts)
+    }
+}
+
+impl<F> IntroFailureManager for OnFailureRetry<F>
+where
+    F: IntroFailureManager,
+{
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
+    where
+        S: States + FreelyMutableState,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        let run_at = app
+            .world()
+            .resource::<IntroPreferences<S, D, U, L>>()
+            .run_at()
+            .clone();
+
+        app.insert_resource(IntroRetries::<L> {
+            attempt: 0,
+            max_attempts: self.attempts,
+            backoff: self.backoff,
+            delay: None,
+            label: PhantomData,
+        })
+        // The attempts start over whenever the intro is entered anew, rather than restarted
+        .add_systems(OnEnter(run_at), reset_retries::<L>)
+        // Failures are caught before they are applied, so only the last one reaches the fallback.
+        // The restart is applied before `Update` as well, so a failing duration can't override it
+        .add_systems(
+            PreUpdate,
+            (intercept_failure::<L>, wait_for_backoff::<L>)
+                .chain()
+                .after(update_intro_time::<L>),
+        )
+        .add_systems(
+            OnEnter(L::State::from(IntroState::Running)),
+            clear_retry_message::<L>,
+        );
+
+        self.fallback
+            .manage_failure_in::<S, D, U, L>(app, schedules);
+    }
+
+    // Failures only get through once every attempt has failed
+    fn handles(&self, reason: Option<&IntroFailureReason>) -> bool {
+        self.fallback.handles(reason)
+    }
+}
+
+fn reset_retries<L: IntroLabel>(mut retries: ResMut<IntroRetries<L>>) {
+    retries.attempt = 0;
+    retries.delay = None;
+}
+
+fn intercept_failure<L: IntroLabel>(
+    mut retries: ResMut<IntroRetries<L>>,
+    mut next_state: ResMut<NextState<L::State>>,
+    mut progress: ResMut<IntroProgress<L>>,
+) {
+    if !matches!(*next_state, NextState::Pending(state) if state.unlabeled().is_failure()) {
+        return;
+    }
+
+    // Failures reported while already waiting belong to the attempt that just failed
+    if retries.is_waiting() {
+        next_state.reset();
+        return;
+    }
+
+    if retries.attempt >= retries.max_attempts {
+        return;
+    }
+
+    next_state.reset();
+    let delay = retries.backoff * 2u32.saturating_pow(retries.attempt);
+    retries.delay = Some(Timer::new(delay, TimerMode::Once));
+    retries.attempt += 1;
+    progress.set_message(retries.retry_message());
+}
+
+fn wait_for_backoff<L: IntroLabel>(
+    mut retries: ResMut<IntroRetries<L>>,
+    mut restart: IntroRestart<L>,
+    time: Res<IntroTime<L>>,
+) {
+    let Some(delay) = retries.delay.as_mut() else {
+        return;
+    };
+
+    if delay.tick(time.delta()).finished() {
+        retries.delay = None;
+        restart.restart();
+    }
+}
+
+fn clear_retry_message<L: IntroLabel>(
+    retries: Res<IntroRetries<L>>,
+    mut progress: ResMut<IntroProgress<L>>,
+) {
+    if progress.message() == Some(retries.retry_message().as_str()) {
+        progress.clear_message();
+    }
+}
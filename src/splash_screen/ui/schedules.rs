@@ -0,0 +1,193 @@
+use std::marker::PhantomData;
+
+use bevy::{
+    ecs::schedule::{InternedScheduleLabel, ScheduleLabel},
+    prelude::*,
+    state::state::FreelyMutableState,
+};
+
+use super::ShowIntroScreen;
+use crate::splash_screen::{
+    state::is_failure_labeled, IntroDuration, IntroLabel, IntroPreferences, IntroState,
+};
+
+/// Where an [super::IntroFailureManager] adds the systems managing a failure of the intro
+/// labelled `L`, see [super::IntroFailureManager::manage_failure_in].
+///
+/// Unless the manager is picked by a combinator like [super::OrElse], these are the `OnEnter`
+/// and `OnExit` schedules of [IntroState::Failure]. Every manager a combinator picks from
+/// gets schedules of its own instead, which only run once it is picked.
+#[derive(Clone, Debug)]
+pub struct IntroFailureSchedules<L = ()>
+where
+    L: IntroLabel,
+{
+    branch: Option<usize>,
+    label: PhantomData<L>,
+}
+
+/// The systems of an [super::IntroFailureManager] that only run while it manages the failure of
+/// the intro labelled `L`, see [IntroFailureSchedules::set].
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntroFailureSet<L = ()>
+where
+    L: IntroLabel,
+{
+    branch: Option<usize>,
+    label: PhantomData<L>,
+}
+
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct BranchEnter<L: IntroLabel>(usize, PhantomData<L>);
+
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct BranchExit<L: IntroLabel>(usize, PhantomData<L>);
+
+// When every branch of the intro labelled `L` was picked, if it is active
+#[derive(Resource)]
+struct FailureBranches<L: IntroLabel> {
+    activated_at: Vec<Option<u64>>,
+    activations: u64,
+    label: PhantomData<L>,
+}
+
+// The schedules the sets are configured in
+fn set_schedules() -> [InternedScheduleLabel; 3] {
+    [PreUpdate.intern(), Update.intern(), PostUpdate.intern()]
+}
+
+impl<L> Default for IntroFailureSchedules<L>
+where
+    L: IntroLabel,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L> IntroFailureSchedules<L>
+where
+    L: IntroLabel,
+{
+    /// Returns the schedules of the `Failure` state itself, used by the plugin for the
+    /// [super::IntroFailureManager] it was created with.
+    pub const fn new() -> Self {
+        Self {
+            branch: None,
+            label: PhantomData,
+        }
+    }
+
+    /// Returns the schedule run once the manager starts managing the failure.
+    pub fn enter(&self) -> InternedScheduleLabel {
+        match self.branch {
+            Some(branch) => BranchEnter::<L>(branch, PhantomData).intern(),
+            None => OnEnter(L::State::from(IntroState::Failure)).intern(),
+        }
+    }
+
+    /// Returns the schedule run once the manager stops managing the failure, either because the
+    /// failure is over or because a combinator moved on to another manager.
+    pub fn exit(&self) -> InternedScheduleLabel {
+        match self.branch {
+            Some(branch) => BranchExit::<L>(branch, PhantomData).intern(),
+            None => OnExit(L::State::from(IntroState::Failure)).intern(),
+        }
+    }
+
+    /// Returns the set of the systems that only run while the manager manages the failure.
+    ///
+    /// The set is configured in `PreUpdate`, `Update` and `PostUpdate`.
+    pub fn set(&self) -> IntroFailureSet<L> {
+        IntroFailureSet {
+            branch: self.branch,
+            label: PhantomData,
+        }
+    }
+
+    // Creates the schedules of a manager picked by a combinator set up within these schedules
+    pub(crate) fn branch(&self, app: &mut App) -> Self {
+        let mut branches = app.world_mut().resource_mut::<FailureBranches<L>>();
+        branches.activated_at.push(None);
+
+        let id = branches.activated_at.len() - 1;
+        let branch = Self {
+            branch: Some(id),
+            label: PhantomData,
+        };
+
+        for schedule in set_schedules() {
+            app.configure_sets(
+                schedule,
+                branch
+                    .set()
+                    .in_set(self.set())
+                    .run_if(move |branches: Res<FailureBranches<L>>| {
+                        branches.activated_at[id].is_some()
+                    }),
+            );
+        }
+
+        // A branch ends along with the schedules it was picked within
+        let ended = branch.clone();
+        app.add_systems(self.exit(), move |world: &mut World| {
+            ended.deactivate(world)
+        });
+
+        branch
+    }
+
+    pub(crate) fn activated_at(&self, world: &World) -> Option<u64> {
+        let branch = self.branch?;
+        world.resource::<FailureBranches<L>>().activated_at[branch]
+    }
+
+    pub(crate) fn activate(&self, world: &mut World) {
+        let Some(branch) = self.branch else {
+            return;
+        };
+
+        let mut branches = world.resource_mut::<FailureBranches<L>>();
+        if branches.activated_at[branch].is_some() {
+            return;
+        }
+
+        branches.activations += 1;
+        branches.activated_at[branch] = Some(branches.activations);
+        world.try_run_schedule(self.enter()).ok();
+    }
+
+    pub(crate) fn deactivate(&self, world: &mut World) {
+        let Some(branch) = self.branch else {
+            return;
+        };
+
+        let mut branches = world.resource_mut::<FailureBranches<L>>();
+        if branches.activated_at[branch].take().is_none() {
+            return;
+        }
+
+        world.try_run_schedule(self.exit()).ok();
+    }
+}
+
+impl<S, D, U, L> IntroPreferences<S, D, U, L>
+where
+    S: States + FreelyMutableState,
+    D: IntroDuration,
+    U: ShowIntroScreen,
+    L: IntroLabel,
+{
+    pub(in crate::splash_screen) fn add_failure_schedules(&self, app: &mut App) {
+        app.insert_resource(FailureBranches::<L> {
+            activated_at: Vec::new(),
+            activations: 0,
+            label: PhantomData,
+        });
+
+        let root = IntroFailureSchedules::<L>::new().set();
+        for schedule in set_schedules() {
+            app.configure_sets(schedule, root.clone().run_if(is_failure_labeled::<L>));
+        }
+    }
+}
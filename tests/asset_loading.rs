@@ -0,0 +1,80 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_intro_screen::prelude::*;
+use common::{app_state, preferences, run_until_running, AppState, FRAME};
+
+#[derive(Asset, TypePath)]
+struct Placeholder;
+
+fn app(duration: AssetLoadingDuration) -> App {
+    let mut app = common::app(preferences(duration));
+    app.add_plugins(AssetPlugin::default())
+        .init_asset::<Placeholder>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+#[test]
+fn loaded_assets_wait_for_min_duration() {
+    let duration = AssetLoadingDuration::builder()
+        .min_duration(Duration::from_secs(1))
+        .max_duration(Duration::from_secs(60))
+        .build();
+
+    let mut app = app(duration);
+    let handle = app
+        .world_mut()
+        .resource_mut::<Assets<Placeholder>>()
+        .add(Placeholder);
+    app.world_mut()
+        .resource_mut::<AssetLoadingProgress>()
+        .track(handle.clone());
+
+    run_until_running(&mut app);
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+    assert_eq!(app.world().resource::<AssetLoadingProgress>().fraction(), 1.0);
+    assert_eq!(app.world().resource::<IntroProgress>().fraction(), Some(1.0));
+
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(app_state(&app), AppState::GameMenu);
+
+    // The handles outlive the intro
+    assert_eq!(app.world().resource::<AssetLoadingProgress>().total(), 0);
+    let loaded = app.world().resource::<IntroLoadedAssets>().handles();
+    assert_eq!(loaded, [handle.untyped()]);
+}
+
+#[test]
+fn failing_asset_fails_the_intro() {
+    let duration = AssetLoadingDuration::builder()
+        .paths(vec!["missing.placeholder".into()])
+        .max_duration(Duration::from_secs(60))
+        .build();
+
+    let mut app = app(duration);
+    run_until_running(&mut app);
+
+    // Loading happens on another thread, so give it some real time to fail
+    for _ in 0..200 {
+        app.update();
+        if app.world().resource::<State<IntroState>>().is_failure() {
+            let reason = app.world().resource::<IntroFailure>().reason();
+            assert!(matches!(
+                reason,
+                Some(IntroFailureReason::AssetLoad { path: Some(path), .. }) if path == "missing.placeholder"
+            ));
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+
+    panic!("the intro never failed")
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "bevy_ui")]
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::{
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState,
+    },
+    prelude::*,
+    time::TimeUpdateStrategy,
+};
+use bevy_intro_screen::prelude::{bevy_ui::*, *};
+use common::{
+    app_state, bevy_ui::HeadlessBevyIntroScreen, run_until_running, update, AppState, FRAME,
+};
+
+fn app(skip_policy: SkipPolicy, ui: HeadlessBevyIntroScreen) -> App {
+    let preferences = IntroPreferences::builder()
+        .run_at(AppState::SplashScreen)
+        .transition_to(AppState::GameMenu)
+        .skip_policy(skip_policy)
+        .duration(FixedDuration::new_with_duration(
+            Duration::from_secs(1),
+            AppState::GameMenu,
+        ))
+        .ui(ui)
+        .build();
+
+    let mut app = common::app(preferences);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+fn progress(app: &App) -> &TransitionProgress {
+    app.world().resource::<TransitionProgress>()
+}
+
+fn key(app: &mut App, state: ButtonState) {
+    app.world_mut().send_event(KeyboardInput {
+        key_code: KeyCode::Space,
+        logical_key: Key::Space,
+        state,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+fn skip_indicator(app: &mut App) -> (Visibility, Val) {
+    let visibility = *app
+        .world_mut()
+        .query_filtered::<&Visibility, With<BevySkipIndicatorMarker>>()
+        .single(app.world());
+    let width = app
+        .world_mut()
+        .query_filtered::<&Style, With<BevySkipIndicatorFillMarker>>()
+        .single(app.world())
+        .width;
+    (visibility, width)
+}
+
+#[test]
+fn exit_transition_holds_the_switch_to_transition_to() {
+    let ui = HeadlessBevyIntroScreen {
+        exit_transition: Some(IntroTransition::fade(FRAME * 5)),
+    };
+    let mut app = app(SkipPolicy::disabled(), ui);
+    run_until_running(&mut app);
+
+    for _ in 0..20 {
+        if progress(&app).phase() == &TransitionPhase::Exiting {
+            break;
+        }
+        app.update();
+    }
+    assert_eq!(progress(&app).phase(), &TransitionPhase::Exiting);
+
+    update(&mut app, 3);
+    assert_eq!(progress(&app).phase(), &TransitionPhase::Exiting);
+    assert!(progress(&app).visibility() > 0.0 && progress(&app).visibility() < 1.0);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    update(&mut app, 4);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn skip_indicator_follows_the_hold() {
+    let skip_policy = SkipPolicy::builder()
+        .keys(vec![KeyCode::Space])
+        .hold(Duration::from_millis(800))
+        .build();
+    let mut app = app(skip_policy, HeadlessBevyIntroScreen::default());
+    run_until_running(&mut app);
+    assert_eq!(skip_indicator(&mut app).0, Visibility::Hidden);
+
+    key(&mut app, ButtonState::Pressed);
+    update(&mut app, 4);
+    let fraction = app.world().resource::<SkipHoldProgress>().fraction();
+    assert!(fraction > 0.0 && fraction < 1.0);
+    assert_eq!(
+        skip_indicator(&mut app),
+        (Visibility::Inherited, Val::Percent(fraction * 100.0))
+    );
+
+    key(&mut app, ButtonState::Released);
+    update(&mut app, 2);
+    assert_eq!(
+        skip_indicator(&mut app),
+        (Visibility::Hidden, Val::Percent(0.0))
+    );
+}
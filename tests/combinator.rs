@@ -0,0 +1,99 @@
+mod common;
+
+use std::{collections::HashSet, time::Duration};
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_intro_screen::prelude::*;
+use common::{app_state, preferences, run_until_running, update, AppState, Handled, Record, FRAME};
+
+fn is_timeout(reason: Option<&IntroFailureReason>) -> bool {
+    matches!(reason, Some(IntroFailureReason::Timeout(_)))
+}
+
+fn app<D, F>(duration: D, failure_manager: F) -> App
+where
+    D: IntroDuration,
+    F: IntroFailureManager + Clone,
+{
+    let mut app = common::app_with_failure_manager(preferences(duration), failure_manager);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+fn fixed() -> FixedDuration<AppState> {
+    FixedDuration::new_with_duration(Duration::from_secs(60), AppState::GameMenu)
+}
+
+fn fail(app: &mut App) {
+    run_until_running(app);
+    app.world_mut()
+        .resource_mut::<IntroFailure>()
+        .fail("something went wrong");
+    update(app, 3);
+}
+
+fn handled(app: &App) -> &Handled {
+    app.world().resource::<Handled>()
+}
+
+fn dismiss(app: &mut App) {
+    app.world_mut().send_event(DismissIntroFailure::<()>::default());
+    update(app, 2);
+}
+
+#[test]
+fn or_else_runs_the_first_manager_handling_the_failure() {
+    let manager = Record("timeout").when(is_timeout).or_else(Record("other"));
+
+    let mut failed = app(fixed(), manager.clone());
+    fail(&mut failed);
+    assert_eq!(handled(&failed).entered, ["other"]);
+    assert_eq!(handled(&failed).updated, HashSet::from(["other"]));
+
+    let mut timed_out = app(GenericDynamicDuration::new(Duration::from_secs(1)), manager);
+    update(&mut timed_out, 20);
+    assert_eq!(handled(&timed_out).entered, ["timeout"]);
+    assert_eq!(handled(&timed_out).updated, HashSet::from(["timeout"]));
+}
+
+#[test]
+fn when_ignores_other_failures() {
+    let mut app = app(fixed(), Record("timeout").when(is_timeout));
+    fail(&mut app);
+
+    assert!(handled(&app).entered.is_empty());
+    assert!(handled(&app).updated.is_empty());
+}
+
+#[test]
+fn then_waits_until_the_failure_is_dismissed() {
+    let mut app = app(fixed(), Record("message").then(OnFailureContinue));
+    fail(&mut app);
+
+    assert_eq!(handled(&app).entered, ["message"]);
+    update(&mut app, 10);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    dismiss(&mut app);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn then_steps_are_dismissed_one_at_a_time() {
+    let manager = Record("first")
+        .then(Record("second"))
+        .then(Record("third").then(Record("fourth")));
+
+    let mut app = app(fixed(), manager);
+    fail(&mut app);
+    assert_eq!(handled(&app).entered, ["first"]);
+
+    for expected in [
+        &["first", "second"][..],
+        &["first", "second", "third"],
+        &["first", "second", "third", "fourth"],
+    ] {
+        dismiss(&mut app);
+        assert_eq!(handled(&app).entered, expected);
+    }
+}
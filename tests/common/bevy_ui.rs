@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use bevy_intro_screen::{
+    bevy_asset_loader::prelude::AssetCollection,
+    prelude::{bevy_ui::*, *},
+};
+
+use super::HeadlessIntroScreen;
+
+/// Assets for a [BevyIntroScreen] that are never loaded.
+#[derive(Resource)]
+pub struct HeadlessAssets {
+    icon: Handle<Image>,
+}
+
+impl AssetCollection for HeadlessAssets {
+    fn create(_: &mut World) -> Self {
+        Self {
+            icon: Handle::default(),
+        }
+    }
+
+    fn load(_: &mut World) -> Vec<UntypedHandle> {
+        Vec::new()
+    }
+}
+
+impl IntroScreenAssets for HeadlessAssets {
+    fn background(&self) -> Option<&Handle<Image>> {
+        None
+    }
+
+    fn icon(&self) -> &Handle<Image> {
+        &self.icon
+    }
+
+    fn label(&self) -> String {
+        String::from("My Game")
+    }
+}
+
+/// A [BevyIntroScreen] that doesn't wait for its assets to load, so it can run in a headless `App`.
+#[derive(Clone, Default)]
+pub struct HeadlessBevyIntroScreen {
+    pub exit_transition: Option<IntroTransition>,
+}
+
+impl ShowIntroScreen for HeadlessBevyIntroScreen {
+    fn configure_ui<S, D, U, L>(&self, app: &mut App, preferences: &IntroPreferences<S, D, U, L>)
+    where
+        S: States,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        let mut screen = BevyIntroScreen::<HeadlessAssets>::default();
+        if let Some(transition) = self.exit_transition {
+            screen = screen.with_exit_transition(transition);
+        }
+
+        screen.configure_ui(app, preferences);
+        HeadlessIntroScreen.configure_ui(app, preferences);
+
+        let assets = HeadlessAssets::create(app.world_mut());
+        app.insert_resource(assets);
+    }
+}
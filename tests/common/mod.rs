@@ -0,0 +1,168 @@
+#![allow(dead_code)]
+
+use std::{collections::HashSet, time::Duration};
+
+use bevy::{
+    input::InputPlugin, prelude::*, state::app::StatesPlugin, state::state::FreelyMutableState,
+};
+use bevy_intro_screen::prelude::*;
+
+#[cfg(feature = "bevy_ui")]
+pub mod bevy_ui;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+pub enum AppState {
+    #[default]
+    SplashScreen,
+
+    GameMenu,
+
+    ErrorScreen,
+}
+
+/// An intro screen without any content, so the intro can run in a headless `App`.
+#[derive(Clone, Resource)]
+pub struct HeadlessIntroScreen;
+
+impl ShowIntroScreen for HeadlessIntroScreen {
+    fn configure_ui<S, D, U, L>(&self, app: &mut App, _: &IntroPreferences<S, D, U, L>)
+    where
+        S: States,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        app.add_systems(OnEnter(L::State::from(IntroState::Loading)), finish_loading::<L>);
+    }
+}
+
+fn finish_loading<L: IntroLabel>(mut next_state: ResMut<NextState<L::State>>) {
+    next_state.set(IntroState::Running.into())
+}
+
+/// How long a frame lasts in the tests advancing time manually using `TimeUpdateStrategy`.
+pub const FRAME: Duration = Duration::from_millis(100);
+
+/// Creates the preferences of an intro at `SplashScreen` that can not be skipped, switching to
+/// `GameMenu` once `duration` has finished.
+pub fn preferences<D>(duration: D) -> IntroPreferences<AppState, D, HeadlessIntroScreen>
+where
+    D: IntroDuration,
+{
+    IntroPreferences::builder()
+        .run_at(AppState::SplashScreen)
+        .transition_to(AppState::GameMenu)
+        .skip_policy(SkipPolicy::disabled())
+        .duration(duration)
+        .ui(HeadlessIntroScreen)
+        .build()
+}
+
+/// A duration the intro doesn't finish within during a test, unless it fails.
+pub fn long_duration() -> FixedDuration<AppState> {
+    FixedDuration::new_with_duration(Duration::from_secs(60), AppState::GameMenu)
+}
+
+/// Creates a headless `App` running the intro described by `preferences`.
+pub fn app<D, U>(preferences: IntroPreferences<AppState, D, U>) -> App
+where
+    D: IntroDuration,
+    U: ShowIntroScreen + Clone,
+{
+    app_with_failure_manager(preferences, OnFailureContinue)
+}
+
+/// Creates a headless `App` running the intro described by `preferences`, handling failures
+/// using `failure_manager`.
+pub fn app_with_failure_manager<D, U, F>(
+    preferences: IntroPreferences<AppState, D, U>,
+    failure_manager: F,
+) -> App
+where
+    D: IntroDuration,
+    U: ShowIntroScreen + Clone,
+    F: IntroFailureManager + Clone,
+{
+    let intro_plugin = IntroScreenPlugin::builder()
+        .preferences(preferences)
+        .failure_manager(failure_manager)
+        .build();
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, InputPlugin))
+        .init_state::<AppState>()
+        .add_plugins(intro_plugin);
+    app
+}
+
+/// Updates the `App` until the intro screen is running.
+pub fn run_until_running(app: &mut App) {
+    for _ in 0..10 {
+        app.update();
+        if app.world().resource::<State<IntroState>>().is_running() {
+            return;
+        }
+    }
+
+    panic!("the intro screen never started running")
+}
+
+/// Updates the `App` `frames` times.
+pub fn update(app: &mut App, frames: usize) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+pub fn app_state(app: &App) -> AppState {
+    *app.world().resource::<State<AppState>>().get()
+}
+
+/// Whether the intro has failed, recorded once [record_failures] is used.
+#[derive(Resource, Default)]
+pub struct Failed(pub bool);
+
+/// Records whether an [IntroFailed] has been sent into [Failed].
+pub fn record_failures(app: &mut App) {
+    app.init_resource::<Failed>()
+        .add_systems(Last, record_failure);
+}
+
+fn record_failure(mut failed: ResMut<Failed>, mut events: EventReader<IntroFailed>) {
+    failed.0 |= events.read().count() > 0;
+}
+
+/// A failure manager recording when it starts managing a failure and whether it is updated
+/// while the failure lasts, see [Handled].
+#[derive(Clone)]
+pub struct Record(pub &'static str);
+
+/// The failures handled by every [Record], by name.
+#[derive(Resource, Default)]
+pub struct Handled {
+    pub entered: Vec<&'static str>,
+    pub updated: HashSet<&'static str>,
+}
+
+impl IntroFailureManager for Record {
+    fn manage_failure_in<S, D, U, L>(&self, app: &mut App, schedules: IntroFailureSchedules<L>)
+    where
+        S: States + FreelyMutableState,
+        D: IntroDuration,
+        U: ShowIntroScreen,
+        L: IntroLabel,
+    {
+        let name = self.0;
+        app.init_resource::<Handled>()
+            .add_systems(schedules.enter(), move |mut handled: ResMut<Handled>| {
+                handled.entered.push(name)
+            })
+            .add_systems(
+                Update,
+                (move |mut handled: ResMut<Handled>| {
+                    handled.updated.insert(name);
+                })
+                .in_set(schedules.set()),
+            );
+    }
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_intro_screen::prelude::*;
+use common::{
+    app_state, long_duration, preferences, run_until_running, update, AppState, Handled, Record,
+    FRAME,
+};
+
+fn app<F: IntroFailureManager + Clone>(failure_manager: F) -> App {
+    let mut app = common::app_with_failure_manager(preferences(long_duration()), failure_manager);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    run_until_running(&mut app);
+
+    app.world_mut()
+        .resource_mut::<IntroFailure>()
+        .fail("something went wrong");
+    app.update();
+    app
+}
+
+fn entered(app: &App) -> &[&'static str] {
+    &app.world().resource::<Handled>().entered
+}
+
+#[test]
+fn delayed_manager_waits_for_the_delay() {
+    let mut app = app(OnFailureContinue.delayed(Duration::from_secs(1)));
+
+    update(&mut app, 5);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    update(&mut app, 10);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn every_delay_has_its_own_timer() {
+    let manager = Record("short")
+        .delayed(Duration::from_millis(500))
+        .and(Record("long").delayed(Duration::from_secs(2)));
+
+    let mut app = app(manager);
+    assert!(entered(&app).is_empty());
+    assert!(app.world().resource::<Handled>().updated.is_empty());
+
+    update(&mut app, 8);
+    assert_eq!(entered(&app), ["short"]);
+
+    update(&mut app, 15);
+    assert_eq!(entered(&app), ["short", "long"]);
+}
+
+#[test]
+fn delay_shorthands_are_tuple_structs() {
+    let mut app = app(OnFailureContinueWithDelay(Duration::from_secs(1)));
+
+    update(&mut app, 5);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    update(&mut app, 10);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn delay_waits_while_intro_time_is_paused() {
+    let mut app = app(OnFailureContinue.delayed(Duration::from_secs(1)));
+    app.world_mut().resource_mut::<IntroTime>().pause();
+
+    update(&mut app, 15);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    app.world_mut().resource_mut::<IntroTime>().resume();
+    update(&mut app, 15);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
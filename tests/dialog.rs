@@ -0,0 +1,119 @@
+#![cfg(feature = "bevy_ui")]
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_intro_screen::prelude::{bevy_ui::*, *};
+use common::{
+    app_state, long_duration, preferences, run_until_running, update, AppState, Handled, Record,
+    FRAME,
+};
+
+fn dialog() -> OnFailureShowDialog {
+    OnFailureShowDialog(
+        FailureDialog::builder()
+            .title("Oops")
+            .buttons(vec![
+                FailureDialogButton::Retry,
+                FailureDialogButton::Continue,
+                FailureDialogButton::Dismiss,
+            ])
+            .build(),
+    )
+}
+
+fn fail(app: &mut App) {
+    run_until_running(app);
+    app.world_mut()
+        .resource_mut::<IntroFailure>()
+        .fail("the save file is corrupted");
+    for _ in 0..2 {
+        app.update();
+    }
+    assert_eq!(
+        app.world().resource::<State<IntroState>>().get(),
+        &IntroState::Failure
+    );
+}
+
+fn dialogs(app: &mut App) -> usize {
+    app.world_mut()
+        .query::<&BevyFailureDialogMarker>()
+        .iter(app.world())
+        .count()
+}
+
+fn press(app: &mut App, pressed: FailureDialogButton) {
+    let mut buttons = app
+        .world_mut()
+        .query::<(&BevyFailureDialogButton, &mut Interaction)>();
+    for (button, mut interaction) in buttons.iter_mut(app.world_mut()) {
+        if button.button == pressed {
+            *interaction = Interaction::Pressed;
+        }
+    }
+}
+
+#[test]
+fn continue_button_leaves_the_intro() {
+    let mut app = common::app_with_failure_manager(preferences(long_duration()), dialog());
+    fail(&mut app);
+    assert_eq!(dialogs(&mut app), 1);
+
+    press(&mut app, FailureDialogButton::Continue);
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert_eq!(app_state(&app), AppState::GameMenu);
+    assert_eq!(dialogs(&mut app), 0);
+}
+
+#[test]
+fn retry_button_loads_the_intro_again() {
+    let mut app = common::app_with_failure_manager(
+        preferences(GenericDynamicDuration::new(Duration::from_secs(1))),
+        dialog(),
+    );
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    run_until_running(&mut app);
+
+    update(&mut app, 15);
+    assert!(app.world().resource::<State<IntroState>>().is_failure());
+    assert_eq!(dialogs(&mut app), 1);
+
+    // The maximum duration starts over rather than failing the retry right away
+    press(&mut app, FailureDialogButton::Retry);
+    update(&mut app, 5);
+    assert!(app.world().resource::<State<IntroState>>().is_running());
+    assert_eq!(app.world().resource::<IntroFailure>().reason(), None);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+    assert_eq!(dialogs(&mut app), 0);
+
+    update(&mut app, 10);
+    assert!(app.world().resource::<State<IntroState>>().is_failure());
+    assert_eq!(dialogs(&mut app), 1);
+}
+
+#[test]
+fn dismiss_button_moves_on_to_the_next_manager() {
+    let mut app = common::app_with_failure_manager(
+        preferences(long_duration()),
+        dialog().then(Record("after")),
+    );
+    fail(&mut app);
+    assert_eq!(dialogs(&mut app), 1);
+    assert!(app.world().resource::<Handled>().entered.is_empty());
+
+    app.world_mut()
+        .send_event(FailureDialogChoice::<()>::new(FailureDialogButton::Dismiss));
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert_eq!(app.world().resource::<Handled>().entered, vec!["after"]);
+    assert_eq!(dialogs(&mut app), 0);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_intro_screen::prelude::*;
+use common::{
+    app_state, preferences, record_failures, run_until_running, update, AppState, Failed, FRAME,
+};
+
+fn app(duration: GenericDynamicDuration) -> App {
+    let mut app = common::app(preferences(duration));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    record_failures(&mut app);
+    app
+}
+
+fn complete(app: &mut App) {
+    app.world_mut()
+        .resource_mut::<NextState<DynamicDurationState>>()
+        .set(DynamicDurationState::Completed);
+}
+
+fn failed(app: &App) -> bool {
+    app.world().resource::<Failed>().0
+}
+
+#[test]
+fn completing_before_min_duration_waits_for_it() {
+    let mut app = app(GenericDynamicDuration::new(Duration::from_secs(5))
+        .with_min_duration(Duration::from_secs(2)));
+
+    run_until_running(&mut app);
+    complete(&mut app);
+    update(&mut app, 5);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    update(&mut app, 20);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+    assert!(!failed(&app));
+}
+
+#[test]
+fn completing_after_min_duration_finishes_right_away() {
+    let mut app = app(GenericDynamicDuration::new(Duration::from_secs(5))
+        .with_min_duration(Duration::from_secs(1)));
+
+    run_until_running(&mut app);
+    update(&mut app, 20);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    complete(&mut app);
+    update(&mut app, 2);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+    assert!(!failed(&app));
+}
+
+#[test]
+fn max_duration_fails_after_min_duration() {
+    let mut app = app(GenericDynamicDuration::new(Duration::from_secs(3))
+        .with_min_duration(Duration::from_secs(1)));
+
+    run_until_running(&mut app);
+    update(&mut app, 20);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+    assert!(!failed(&app));
+
+    update(&mut app, 15);
+    assert!(failed(&app));
+}
+
+#[test]
+fn max_duration_fails_before_min_duration() {
+    let mut app = app(GenericDynamicDuration::new(Duration::from_secs(1))
+        .with_min_duration(Duration::from_secs(3)));
+
+    run_until_running(&mut app);
+    update(&mut app, 15);
+    assert!(failed(&app));
+}
+
+#[test]
+fn completing_when_entering_run_at_is_kept() {
+    let mut app = app(GenericDynamicDuration::new(Duration::from_secs(5))
+        .with_min_duration(Duration::from_secs(1)));
+    app.add_systems(
+        OnEnter(AppState::SplashScreen),
+        |mut next_state: ResMut<NextState<DynamicDurationState>>| {
+            next_state.set(DynamicDurationState::Completed)
+        },
+    );
+
+    run_until_running(&mut app);
+    update(&mut app, 15);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+    assert!(!failed(&app));
+}
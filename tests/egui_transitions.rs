@@ -0,0 +1,81 @@
+#![cfg(feature = "egui")]
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::{
+    input::InputPlugin, prelude::*, render::render_resource::Shader, state::app::StatesPlugin,
+    time::TimeUpdateStrategy,
+};
+use bevy_intro_screen::{
+    bevy_egui::egui::ImageSource,
+    prelude::{egui::*, *},
+};
+use common::{app_state, run_until_running, update, AppState, FRAME};
+
+fn app() -> App {
+    let ui = EguiIntroScreen::builder()
+        .icon(ImageSource::Uri("bytes://icon.png".into()))
+        .label("My Game".into())
+        .exit_transition(IntroTransition::fade(FRAME * 5))
+        .build();
+
+    let preferences = IntroPreferences::builder()
+        .run_at(AppState::SplashScreen)
+        .transition_to(AppState::GameMenu)
+        .skip_policy(SkipPolicy::disabled())
+        .duration(FixedDuration::new_with_duration(
+            Duration::from_secs(1),
+            AppState::GameMenu,
+        ))
+        .ui(ui)
+        .build();
+
+    // The egui plugin is added along with the intro, so its assets have to be set up first
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        StatesPlugin,
+        InputPlugin,
+        AssetPlugin::default(),
+        WindowPlugin::default(),
+    ))
+    .init_asset::<Image>()
+    .init_asset::<Shader>()
+    .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+    .init_state::<AppState>()
+    .add_plugins(
+        IntroScreenPlugin::builder()
+            .preferences(preferences)
+            .failure_manager(OnFailureContinue)
+            .build(),
+    );
+    app
+}
+
+fn progress(app: &App) -> &TransitionProgress {
+    app.world().resource::<TransitionProgress>()
+}
+
+#[test]
+fn exit_transition_holds_the_switch_to_transition_to() {
+    let mut app = app();
+    run_until_running(&mut app);
+
+    for _ in 0..20 {
+        if progress(&app).phase() == &TransitionPhase::Exiting {
+            break;
+        }
+        app.update();
+    }
+    assert_eq!(progress(&app).phase(), &TransitionPhase::Exiting);
+
+    update(&mut app, 3);
+    assert_eq!(progress(&app).phase(), &TransitionPhase::Exiting);
+    assert!(progress(&app).visibility() > 0.0 && progress(&app).visibility() < 1.0);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    update(&mut app, 4);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_intro_screen::prelude::*;
+use common::{preferences, AppState};
+
+#[derive(Resource, Default)]
+struct Lifecycle(Vec<&'static str>);
+
+fn record(
+    mut lifecycle: ResMut<Lifecycle>,
+    mut started: EventReader<IntroStarted>,
+    mut running: EventReader<IntroRunning>,
+    mut finished: EventReader<IntroFinished>,
+    mut skipped: EventReader<IntroSkipped>,
+    mut failed: EventReader<IntroFailed>,
+) {
+    lifecycle.0.extend(started.read().map(|_| "started"));
+    lifecycle.0.extend(running.read().map(|_| "running"));
+    lifecycle.0.extend(finished.read().map(|_| "finished"));
+    lifecycle.0.extend(skipped.read().map(|_| "skipped"));
+    lifecycle.0.extend(failed.read().map(|_| "failed"));
+}
+
+#[test]
+fn finished_intro_sends_lifecycle_events() {
+    let duration = FixedDuration::new_with_duration(Duration::ZERO, AppState::GameMenu);
+    let mut app = common::app(preferences(duration));
+    app.init_resource::<Lifecycle>()
+        .add_systems(Last, record);
+
+    for _ in 0..10 {
+        app.update();
+    }
+
+    assert_eq!(common::app_state(&app), AppState::GameMenu);
+    assert_eq!(
+        app.world().resource::<Lifecycle>().0,
+        ["started", "running", "finished"]
+    );
+}
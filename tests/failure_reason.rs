@@ -0,0 +1,85 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_intro_screen::prelude::*;
+use common::{app_state, preferences, run_until_running, AppState, FRAME};
+
+#[derive(Resource, Default)]
+struct Reasons(Vec<Option<IntroFailureReason>>);
+
+fn record_reasons(mut reasons: ResMut<Reasons>, mut events: EventReader<IntroFailed>) {
+    reasons.0.extend(events.read().map(|event| event.reason.clone()));
+}
+
+fn app<D: IntroDuration>(duration: D) -> App {
+    let mut app = common::app(preferences(duration));
+    app.init_resource::<Reasons>()
+        .add_systems(Last, record_reasons)
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+fn update_until_menu(app: &mut App) {
+    for _ in 0..200 {
+        app.update();
+        if app_state(app) == AppState::GameMenu {
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+
+    panic!("the intro never finished")
+}
+
+fn reasons(app: &App) -> &[Option<IntroFailureReason>] {
+    &app.world().resource::<Reasons>().0
+}
+
+#[test]
+fn dynamic_duration_reports_timeout() {
+    let mut app = app(GenericDynamicDuration::new(Duration::from_secs(1)));
+    update_until_menu(&mut app);
+
+    let timeout = IntroFailureReason::Timeout(Duration::from_secs(1));
+    assert_eq!(reasons(&app), [Some(timeout.clone())]);
+    assert_eq!(app.world().resource::<IntroFailure>().reason(), Some(&timeout));
+}
+
+#[cfg(feature = "tasks")]
+#[test]
+fn failed_task_is_reported() {
+    let duration = TaskDuration::new(Duration::from_secs(60))
+        .with_blocking_task("download", |_| Err(String::from("no connection")));
+
+    let mut app = app(duration);
+    update_until_menu(&mut app);
+
+    assert_eq!(
+        reasons(&app),
+        [Some(IntroFailureReason::Task {
+            name: String::from("download"),
+            error: String::from("no connection"),
+        })]
+    );
+}
+
+#[test]
+fn game_can_fail_the_intro() {
+    let mut app = app(FixedDuration::new_with_duration(
+        Duration::from_secs(60),
+        AppState::GameMenu,
+    ));
+    run_until_running(&mut app);
+
+    app.world_mut()
+        .resource_mut::<IntroFailure>()
+        .fail("save file is corrupted");
+    update_until_menu(&mut app);
+
+    assert_eq!(
+        reasons(&app),
+        [Some(IntroFailureReason::from("save file is corrupted"))]
+    );
+}
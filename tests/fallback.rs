@@ -0,0 +1,111 @@
+#![cfg(feature = "bevy_ui")]
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_intro_screen::prelude::{bevy_ui::*, *};
+use common::{app_state, long_duration, preferences, run_until_running, update, AppState, FRAME};
+
+fn app() -> App {
+    app_with_image(None)
+}
+
+fn app_with_image(image: Option<Handle<Image>>) -> App {
+    let preferences = preferences(long_duration());
+
+    let failure_manager = OnFailureShowFallback::builder()
+        .label("My Game")
+        .duration(Duration::from_secs(1))
+        .maybe_image(image)
+        .build();
+
+    let mut app = common::app_with_failure_manager(preferences, failure_manager);
+    app.add_plugins(AssetPlugin::default())
+        .init_asset::<Image>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+fn fail(app: &mut App) {
+    app.world_mut()
+        .resource_mut::<NextState<IntroState>>()
+        .set(IntroState::Failure);
+    app.update();
+}
+
+fn fallback_image(app: &mut App) -> Handle<Image> {
+    app.world_mut()
+        .query::<&UiImage>()
+        .single(app.world())
+        .texture
+        .clone()
+}
+
+fn fallback_screens(app: &mut App) -> usize {
+    app.world_mut()
+        .query::<&BevyFallbackMarker>()
+        .iter(app.world())
+        .count()
+}
+
+#[test]
+fn fallback_is_shown_for_the_remaining_duration() {
+    let mut app = app();
+    run_until_running(&mut app);
+    for _ in 0..4 {
+        app.update();
+    }
+
+    fail(&mut app);
+    assert_eq!(fallback_screens(&mut app), 1);
+
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(app_state(&app), AppState::GameMenu);
+    assert_eq!(fallback_screens(&mut app), 0);
+}
+
+#[test]
+fn fallback_waits_while_intro_time_is_paused() {
+    let mut app = app();
+    run_until_running(&mut app);
+
+    fail(&mut app);
+    app.world_mut().resource_mut::<IntroTime>().pause();
+    update(&mut app, 20);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+    assert_eq!(fallback_screens(&mut app), 1);
+
+    app.world_mut().resource_mut::<IntroTime>().resume();
+    update(&mut app, 12);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn fallback_shows_the_embedded_image() {
+    let mut app = app();
+    run_until_running(&mut app);
+
+    fail(&mut app);
+    let image = fallback_image(&mut app);
+    let size = app.world().resource::<Assets<Image>>().get(&image).unwrap().size();
+    assert_eq!(size, UVec2::splat(64));
+}
+
+#[test]
+fn fallback_image_can_be_replaced() {
+    let image = Handle::weak_from_u128(0x6a4c_17e2);
+    let mut app = app_with_image(Some(image.clone()));
+    run_until_running(&mut app);
+
+    fail(&mut app);
+    assert_eq!(fallback_image(&mut app), image);
+}
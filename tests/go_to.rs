@@ -0,0 +1,66 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_intro_screen::prelude::*;
+use common::{app_state, long_duration, preferences, run_until_running, AppState};
+
+fn app() -> App {
+    common::app_with_failure_manager(
+        preferences(long_duration()),
+        OnFailureGoTo(AppState::ErrorScreen),
+    )
+}
+
+fn fail(app: &mut App, reason: &'static str) {
+    app.world_mut().resource_mut::<IntroFailure>().fail(reason);
+    for _ in 0..3 {
+        app.update();
+    }
+}
+
+fn enter(app: &mut App, state: AppState) {
+    app.world_mut()
+        .resource_mut::<NextState<AppState>>()
+        .set(state);
+    app.update();
+}
+
+#[test]
+fn failure_goes_to_the_given_state_with_its_reason() {
+    let mut app = app();
+    run_until_running(&mut app);
+
+    fail(&mut app, "save file is corrupted");
+    assert_eq!(app_state(&app), AppState::ErrorScreen);
+    assert_eq!(
+        app.world().resource::<IntroFailureDetails>().reason(),
+        Some(&IntroFailureReason::from("save file is corrupted"))
+    );
+
+    enter(&mut app, AppState::GameMenu);
+    assert!(!app.world().contains_resource::<IntroFailureDetails>());
+}
+
+#[test]
+fn intro_plays_again_after_going_to_the_given_state() {
+    let mut app = app();
+    run_until_running(&mut app);
+
+    fail(&mut app, "save file is corrupted");
+    assert_eq!(app_state(&app), AppState::ErrorScreen);
+    assert_eq!(
+        *app.world().resource::<State<IntroState>>().get(),
+        IntroState::Idle
+    );
+
+    enter(&mut app, AppState::SplashScreen);
+    assert!(!app.world().contains_resource::<IntroFailureDetails>());
+    run_until_running(&mut app);
+
+    fail(&mut app, "no connection");
+    assert_eq!(app_state(&app), AppState::ErrorScreen);
+    assert_eq!(
+        app.world().resource::<IntroFailureDetails>().reason(),
+        Some(&IntroFailureReason::from("no connection"))
+    );
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, state::app::StatesPlugin, time::TimeUpdateStrategy};
+use bevy_intro_screen::prelude::*;
+use common::{update, HeadlessIntroScreen, FRAME};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+enum GameState {
+    #[default]
+    Splash,
+    Menu,
+    Loading,
+    Level,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct LevelLoadingScreen;
+
+impl IntroLabel for LevelLoadingScreen {
+    type State = LabeledIntroState<Self>;
+    type DynamicState = LabeledDynamicDurationState<Self>;
+}
+
+fn game_state(app: &App) -> GameState {
+    *app.world().resource::<State<GameState>>().get()
+}
+
+fn app() -> App {
+    let splash = IntroPreferences::builder()
+        .run_at(GameState::Splash)
+        .transition_to(GameState::Menu)
+        .skip_policy(SkipPolicy::default())
+        .duration(FixedDuration::new_with_duration(
+            Duration::from_secs(1),
+            GameState::Menu,
+        ))
+        .ui(HeadlessIntroScreen)
+        .build();
+
+    let loading_screen = IntroPreferences::builder()
+        .run_at(GameState::Loading)
+        .transition_to(GameState::Level)
+        .skip_policy(SkipPolicy::disabled())
+        .duration(FixedDuration::new_with_duration(
+            Duration::from_secs(2),
+            GameState::Level,
+        ))
+        .ui(HeadlessIntroScreen)
+        .build()
+        .with_label(LevelLoadingScreen);
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, InputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+        .init_state::<GameState>()
+        .add_plugins(
+            IntroScreenPlugin::builder()
+                .preferences(splash)
+                .failure_manager(OnFailureContinue)
+                .build(),
+        )
+        .add_plugins(
+            IntroScreenPlugin::builder()
+                .preferences(loading_screen)
+                .failure_manager(OnFailureContinue)
+                .build(),
+        );
+    app
+}
+
+#[test]
+fn labelled_intros_run_independently() {
+    let mut app = app();
+
+    update(&mut app, 5);
+    assert!(app.world().resource::<State<IntroState>>().is_running());
+    assert_eq!(
+        **app
+            .world()
+            .resource::<State<LabeledIntroState<LevelLoadingScreen>>>()
+            .get(),
+        IntroState::Idle
+    );
+
+    update(&mut app, 15);
+    assert_eq!(game_state(&app), GameState::Menu);
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Loading);
+
+    // The splash screen's timer must not cut the loading screen short
+    update(&mut app, 10);
+    assert_eq!(game_state(&app), GameState::Loading);
+    assert!(app
+        .world()
+        .resource::<State<LabeledIntroState<LevelLoadingScreen>>>()
+        .is_running());
+    assert_eq!(
+        *app.world().resource::<State<IntroState>>().get(),
+        IntroState::Idle
+    );
+
+    update(&mut app, 20);
+    assert_eq!(game_state(&app), GameState::Level);
+}
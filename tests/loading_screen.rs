@@ -0,0 +1,116 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, state::app::StatesPlugin, time::TimeUpdateStrategy};
+use bevy_intro_screen::prelude::*;
+use common::{update, HeadlessIntroScreen, FRAME};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+enum GameState {
+    #[default]
+    Menu,
+    Loading,
+    Level(u8),
+}
+
+fn app() -> App {
+    let preferences = IntroPreferences::builder()
+        .run_at(GameState::Loading)
+        .skip_policy(SkipPolicy::disabled())
+        .duration(FixedDuration::new_with_duration(
+            Duration::from_secs(1),
+            // Without `transition_to`, `run_at` stands in for the destination
+            GameState::Loading,
+        ))
+        .ui(HeadlessIntroScreen)
+        .load_on(TransitionRule::new(|_, to| matches!(to, GameState::Level(_))))
+        .build();
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, InputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+        .init_state::<GameState>()
+        .add_plugins(
+            IntroScreenPlugin::builder()
+                .preferences(preferences)
+                .failure_manager(OnFailureContinue)
+                .build(),
+        );
+    app
+}
+
+fn go_to(app: &mut App, state: GameState) {
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(state);
+}
+
+fn game_state(app: &App) -> GameState {
+    *app.world().resource::<State<GameState>>().get()
+}
+
+fn destination(app: &App) -> Option<GameState> {
+    app.world()
+        .resource::<LoadingDestination<GameState>>()
+        .get()
+        .copied()
+}
+
+#[test]
+fn matching_transitions_show_the_loading_screen() {
+    let mut app = app();
+    update(&mut app, 2);
+
+    for level in 1..=3 {
+        go_to(&mut app, GameState::Level(level));
+        update(&mut app, 5);
+        assert_eq!(game_state(&app), GameState::Loading, "level {level}");
+        assert_eq!(destination(&app), Some(GameState::Level(level)));
+
+        update(&mut app, 15);
+        assert_eq!(game_state(&app), GameState::Level(level), "level {level}");
+        assert_eq!(destination(&app), None);
+    }
+}
+
+#[test]
+fn other_transitions_skip_the_loading_screen() {
+    let mut app = app();
+    go_to(&mut app, GameState::Level(1));
+    update(&mut app, 20);
+
+    go_to(&mut app, GameState::Menu);
+    update(&mut app, 2);
+    assert_eq!(game_state(&app), GameState::Menu);
+}
+
+#[test]
+#[should_panic(expected = "`transition_to` has to be set")]
+fn transition_to_is_required_without_load_on() {
+    IntroPreferences::builder()
+        .run_at(GameState::Loading)
+        .skip_policy(SkipPolicy::disabled())
+        .duration(FixedDuration::new(GameState::Menu))
+        .ui(HeadlessIntroScreen)
+        .build();
+}
+
+#[test]
+fn level_requested_while_entering_a_level_shows_the_loading_screen() {
+    let mut app = app();
+    app.add_systems(
+        OnEnter(GameState::Level(1)),
+        |mut next_state: ResMut<NextState<GameState>>| next_state.set(GameState::Level(2)),
+    );
+    update(&mut app, 2);
+
+    go_to(&mut app, GameState::Level(1));
+    update(&mut app, 20);
+    assert_eq!(game_state(&app), GameState::Loading);
+    assert_eq!(destination(&app), Some(GameState::Level(2)));
+
+    update(&mut app, 20);
+    assert_eq!(game_state(&app), GameState::Level(2));
+    assert_eq!(destination(&app), None);
+}
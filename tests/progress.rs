@@ -0,0 +1,29 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_intro_screen::prelude::*;
+use common::{app_state, preferences, run_until_running, AppState};
+
+#[test]
+fn progress_is_reset_once_the_intro_finishes() {
+    let preferences = preferences(GenericDynamicDuration::new(Duration::from_secs(60)));
+
+    let mut app = common::app(preferences);
+    run_until_running(&mut app);
+    assert_eq!(app.world().resource::<IntroProgress>().fraction(), None);
+
+    app.world_mut().resource_mut::<IntroProgress>().set(1.5);
+    assert_eq!(app.world().resource::<IntroProgress>().fraction(), Some(1.0));
+
+    app.world_mut()
+        .resource_mut::<NextState<DynamicDurationState>>()
+        .set(DynamicDurationState::Completed);
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert_eq!(app_state(&app), AppState::GameMenu);
+    assert_eq!(app.world().resource::<IntroProgress>().fraction(), None);
+}
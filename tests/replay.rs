@@ -0,0 +1,68 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_intro_screen::prelude::*;
+use common::{app_state, preferences, run_until_running, update, AppState, FRAME};
+
+fn app<D>(duration: D) -> App
+where
+    D: IntroDuration,
+{
+    let mut app = common::app(preferences(duration));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+fn replay(app: &mut App) {
+    app.world_mut()
+        .resource_mut::<NextState<AppState>>()
+        .set(AppState::SplashScreen);
+}
+
+fn complete_dynamic_duration(app: &mut App) {
+    app.world_mut()
+        .resource_mut::<NextState<DynamicDurationState>>()
+        .set(DynamicDurationState::Completed);
+}
+
+#[test]
+fn fixed_duration_plays_every_time_run_at_is_entered() {
+    let mut app = app(FixedDuration::new_with_duration(
+        Duration::from_secs(1),
+        AppState::GameMenu,
+    ));
+
+    for round in 0..3 {
+        if round > 0 {
+            replay(&mut app);
+        }
+
+        run_until_running(&mut app);
+        update(&mut app, 5);
+        assert_eq!(app_state(&app), AppState::SplashScreen, "round {round}");
+
+        update(&mut app, 10);
+        assert_eq!(app_state(&app), AppState::GameMenu, "round {round}");
+    }
+}
+
+#[test]
+fn dynamic_duration_plays_every_time_run_at_is_entered() {
+    let mut app = app(GenericDynamicDuration::new(Duration::from_secs(60)));
+
+    for round in 0..3 {
+        if round > 0 {
+            replay(&mut app);
+        }
+
+        run_until_running(&mut app);
+        update(&mut app, 5);
+        assert_eq!(app_state(&app), AppState::SplashScreen, "round {round}");
+
+        complete_dynamic_duration(&mut app);
+        update(&mut app, 3);
+        assert_eq!(app_state(&app), AppState::GameMenu, "round {round}");
+    }
+}
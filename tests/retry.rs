@@ -0,0 +1,226 @@
+mod common;
+
+use std::time::Duration;
+#[cfg(feature = "tasks")]
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_intro_screen::prelude::*;
+use common::{app_state, long_duration, preferences, run_until_running, update, AppState, FRAME};
+
+fn app(attempts: u32) -> App {
+    let preferences = preferences(long_duration());
+
+    let failure_manager = OnFailureRetry::new(attempts, OnFailureContinue)
+        .with_backoff(Duration::from_millis(500));
+
+    let mut app = common::app_with_failure_manager(preferences, failure_manager);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+fn fail(app: &mut App) {
+    app.world_mut()
+        .resource_mut::<NextState<IntroState>>()
+        .set(IntroState::Failure);
+    app.update();
+}
+
+// The intro keeps running while waiting for the backoff, until it is loaded again
+fn run_until_retried(app: &mut App) {
+    for _ in 0..50 {
+        if !retries(app).is_waiting() {
+            break;
+        }
+        app.update();
+    }
+    app.update();
+    run_until_running(app);
+}
+
+fn retries(app: &App) -> &IntroRetries {
+    app.world().resource::<IntroRetries>()
+}
+
+#[derive(Resource, Default)]
+struct Starts(u32);
+
+fn starts(app: &App) -> u32 {
+    app.world().resource::<Starts>().0
+}
+
+#[test]
+fn failure_is_retried_after_backoff() {
+    let mut app = app(2);
+    run_until_running(&mut app);
+
+    fail(&mut app);
+    assert_eq!(retries(&app).attempt(), 1);
+    assert!(retries(&app).is_waiting());
+    assert_eq!(
+        app.world().resource::<IntroProgress>().message(),
+        Some("Retrying (1/2)")
+    );
+    assert!(app.world().resource::<State<IntroState>>().is_running());
+
+    run_until_retried(&mut app);
+    assert!(!retries(&app).is_waiting());
+    assert_eq!(app.world().resource::<IntroProgress>().message(), None);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+}
+
+#[test]
+fn backoff_doubles_with_every_attempt() {
+    let mut app = app(2);
+    run_until_running(&mut app);
+
+    fail(&mut app);
+    for _ in 0..5 {
+        app.update();
+    }
+    run_until_retried(&mut app);
+
+    fail(&mut app);
+    for _ in 0..5 {
+        app.update();
+    }
+    assert!(retries(&app).is_waiting());
+    for _ in 0..5 {
+        app.update();
+    }
+    assert!(!retries(&app).is_waiting());
+}
+
+#[test]
+fn backoff_delays_every_restart() {
+    let mut app = app(3);
+    app.init_resource::<Starts>().add_systems(
+        OnIntroStart::<()>::default(),
+        |mut starts: ResMut<Starts>| starts.0 += 1,
+    );
+    run_until_running(&mut app);
+
+    // 500ms, 1s and 2s at 100ms a frame
+    for (attempt, backoff) in [(1, 5), (2, 10), (3, 20)] {
+        fail(&mut app);
+        update(&mut app, backoff - 2);
+        assert_eq!(starts(&app), attempt);
+        assert!(retries(&app).is_waiting());
+
+        update(&mut app, 3);
+        assert_eq!(starts(&app), attempt + 1);
+        run_until_running(&mut app);
+    }
+}
+
+#[test]
+fn paused_intro_holds_the_backoff() {
+    let mut app = app(1);
+    run_until_running(&mut app);
+
+    fail(&mut app);
+    app.world_mut().resource_mut::<IntroTime>().pause();
+    update(&mut app, 20);
+    assert!(retries(&app).is_waiting());
+
+    app.world_mut().resource_mut::<IntroTime>().resume();
+    update(&mut app, 5);
+    assert!(!retries(&app).is_waiting());
+}
+
+#[test]
+fn fallback_manages_failure_once_attempts_are_exhausted() {
+    let mut app = app(1);
+    run_until_running(&mut app);
+
+    fail(&mut app);
+    run_until_retried(&mut app);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    fail(&mut app);
+    app.update();
+    assert_eq!(
+        *app.world().resource::<State<IntroState>>().get(),
+        IntroState::Failure
+    );
+    app.update();
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn attempts_start_over_when_the_intro_is_entered_again() {
+    let mut app = app(1);
+    run_until_running(&mut app);
+
+    fail(&mut app);
+    run_until_retried(&mut app);
+    assert_eq!(retries(&app).attempt(), 1);
+
+    app.world_mut()
+        .resource_mut::<NextState<AppState>>()
+        .set(AppState::GameMenu);
+    app.update();
+    app.world_mut()
+        .resource_mut::<NextState<AppState>>()
+        .set(AppState::SplashScreen);
+    run_until_running(&mut app);
+    assert_eq!(retries(&app).attempt(), 0);
+}
+
+#[test]
+fn timed_out_intro_is_retried_with_a_fresh_timeout() {
+    let failure_manager = OnFailureRetry::new(1, OnFailureGoTo(AppState::ErrorScreen))
+        .with_backoff(Duration::from_millis(500));
+    let mut app = common::app_with_failure_manager(
+        preferences(GenericDynamicDuration::new(Duration::from_secs(1))),
+        failure_manager,
+    );
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    run_until_running(&mut app);
+
+    update(&mut app, 12);
+    assert_eq!(retries(&app).attempt(), 1);
+    run_until_retried(&mut app);
+
+    // The maximum duration starts over rather than failing the retry right away
+    update(&mut app, 5);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+    assert!(app.world().resource::<State<IntroState>>().is_running());
+
+    update(&mut app, 10);
+    assert_eq!(app_state(&app), AppState::ErrorScreen);
+}
+
+#[cfg(feature = "tasks")]
+#[test]
+fn failed_task_is_run_again() {
+    let runs = Arc::new(AtomicU32::new(0));
+    let counted = runs.clone();
+
+    let duration = TaskDuration::new(Duration::from_secs(60)).with_blocking_task(
+        "flaky",
+        move |_| match counted.fetch_add(1, Ordering::Relaxed) {
+            0 => Err(String::from("no connection")),
+            _ => Ok(()),
+        },
+    );
+    let failure_manager = OnFailureRetry::new(1, OnFailureGoTo(AppState::ErrorScreen))
+        .with_backoff(Duration::ZERO);
+    let mut app = common::app_with_failure_manager(preferences(duration), failure_manager);
+
+    // The tasks run on other threads, so give them some real time to finish
+    for _ in 0..200 {
+        app.update();
+        if app_state(&app) != AppState::SplashScreen {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+
+    assert_eq!(app_state(&app), AppState::GameMenu);
+    assert_eq!(runs.load(Ordering::Relaxed), 2);
+    assert_eq!(retries(&app).attempt(), 1);
+}
@@ -0,0 +1,169 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::{
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState,
+    },
+    prelude::*,
+    time::TimeUpdateStrategy,
+};
+use bevy_intro_screen::prelude::*;
+use common::{app_state, run_until_running, update, AppState, HeadlessIntroScreen, FRAME};
+
+fn slide(duration: Duration) -> IntroSlide<HeadlessIntroScreen> {
+    IntroSlide::builder()
+        .ui(HeadlessIntroScreen)
+        .duration(duration)
+        .build()
+}
+
+fn app(skip_policy: SkipPolicy) -> App {
+    app_with_slides(
+        [slide(Duration::from_secs(1)), slide(Duration::from_secs(3))],
+        skip_policy,
+    )
+}
+
+fn app_with_slides(
+    slides: impl IntoIterator<Item = IntroSlide<HeadlessIntroScreen>>,
+    skip_policy: SkipPolicy,
+) -> App {
+    let sequence = IntroSequence::new(slides);
+
+    let preferences = IntroPreferences::builder()
+        .run_at(AppState::SplashScreen)
+        .transition_to(AppState::GameMenu)
+        .skip_policy(skip_policy)
+        .duration(sequence.clone())
+        .ui(sequence)
+        .build();
+
+    let mut app = common::app(preferences);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+fn current_slide(app: &App) -> usize {
+    *app.world().resource::<CurrentIntroSlide>().index()
+}
+
+fn press(app: &mut App, key_code: KeyCode) {
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key: Key::Space,
+        state: ButtonState::Pressed,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+#[test]
+fn slides_are_shown_in_order_for_their_own_duration() {
+    let mut app = app(SkipPolicy::disabled());
+    run_until_running(&mut app);
+    assert_eq!(current_slide(&app), 0);
+
+    update(&mut app, 8);
+    assert_eq!(current_slide(&app), 0);
+
+    // Going back through loading takes a couple of frames
+    update(&mut app, 5);
+    assert_eq!(current_slide(&app), 1);
+    assert!(app.world().resource::<State<IntroState>>().is_running());
+
+    update(&mut app, 20);
+    assert_eq!(current_slide(&app), 1);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    update(&mut app, 15);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn sequence_is_rewound_when_run_at_is_entered_again() {
+    let mut app = app(SkipPolicy::disabled());
+    run_until_running(&mut app);
+    update(&mut app, 50);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+    assert_eq!(current_slide(&app), 1);
+
+    app.world_mut()
+        .resource_mut::<NextState<AppState>>()
+        .set(AppState::SplashScreen);
+    run_until_running(&mut app);
+    assert_eq!(current_slide(&app), 0);
+
+    update(&mut app, 8);
+    assert_eq!(current_slide(&app), 0);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+}
+
+#[test]
+fn skipping_a_slide_skips_the_whole_sequence() {
+    let mut app = app(SkipPolicy::default());
+    run_until_running(&mut app);
+    update(&mut app, 2);
+    assert_eq!(current_slide(&app), 0);
+
+    press(&mut app, KeyCode::Space);
+    update(&mut app, 3);
+
+    assert_eq!(app_state(&app), AppState::GameMenu);
+    assert_eq!(current_slide(&app), 0);
+}
+
+#[test]
+fn slides_that_are_not_skippable_are_sat_through() {
+    let unskippable = IntroSlide::builder()
+        .ui(HeadlessIntroScreen)
+        .duration(Duration::from_secs(1))
+        .skippable(false)
+        .build();
+    let mut app = app_with_slides(
+        [unskippable, slide(Duration::from_secs(3))],
+        SkipPolicy::default(),
+    );
+    run_until_running(&mut app);
+
+    press(&mut app, KeyCode::Space);
+    update(&mut app, 3);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+    assert_eq!(current_slide(&app), 0);
+
+    update(&mut app, 10);
+    assert_eq!(current_slide(&app), 1);
+    run_until_running(&mut app);
+
+    press(&mut app, KeyCode::Enter);
+    update(&mut app, 3);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn every_slide_opens_its_own_unskippable_window() {
+    let publisher = IntroSlide::builder()
+        .ui(HeadlessIntroScreen)
+        .duration(Duration::from_secs(1))
+        .build();
+    let game = IntroSlide::builder()
+        .ui(HeadlessIntroScreen)
+        .duration(Duration::from_secs(3))
+        .unskippable_for(Duration::from_secs(1))
+        .build();
+    let mut app = app_with_slides([publisher, game], SkipPolicy::default());
+    run_until_running(&mut app);
+    update(&mut app, 12);
+    run_until_running(&mut app);
+    assert_eq!(current_slide(&app), 1);
+
+    press(&mut app, KeyCode::Space);
+    update(&mut app, 3);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    update(&mut app, 10);
+    press(&mut app, KeyCode::Enter);
+    update(&mut app, 3);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
@@ -0,0 +1,282 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::{
+    input::{
+        gamepad::{GamepadButtonChangedEvent, GamepadEvent},
+        keyboard::{Key, KeyboardInput},
+        touch::{TouchInput, TouchPhase},
+        ButtonState,
+    },
+    ecs::system::RunSystemOnce,
+    prelude::*,
+    time::TimeUpdateStrategy,
+};
+use bevy_intro_screen::prelude::*;
+use common::{app_state, run_until_running, update, AppState, HeadlessIntroScreen, FRAME};
+
+fn app(skip_policy: SkipPolicy) -> App {
+    let preferences = IntroPreferences::builder()
+        .run_at(AppState::SplashScreen)
+        .transition_to(AppState::GameMenu)
+        .skip_policy(skip_policy)
+        .duration(FixedDuration::new_with_duration(
+            Duration::from_secs(60),
+            AppState::GameMenu,
+        ))
+        .ui(HeadlessIntroScreen)
+        .build();
+
+    common::app(preferences)
+}
+
+fn press_gamepad_button(app: &mut App, button_type: GamepadButtonType) {
+    let event = GamepadButtonChangedEvent::new(Gamepad::new(0), button_type, 1.0);
+    app.world_mut().send_event(GamepadEvent::Button(event));
+}
+
+fn key(app: &mut App, key_code: KeyCode, state: ButtonState) {
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key: Key::Space,
+        state,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+fn hold_progress(app: &App) -> &SkipHoldProgress {
+    app.world().resource::<SkipHoldProgress>()
+}
+
+fn tap(app: &mut App) {
+    app.world_mut().send_event(TouchInput {
+        phase: TouchPhase::Started,
+        position: Vec2::ZERO,
+        window: Entity::PLACEHOLDER,
+        force: None,
+        id: 0,
+    });
+}
+
+// Input is read in one frame, `IntroState::Skipped` is entered in the next and the
+// switch to `transition_to` is applied in the one after
+fn skip_frames(app: &mut App) {
+    for _ in 0..3 {
+        app.update();
+    }
+}
+
+#[test]
+fn gamepad_button_skips_intro() {
+    let mut app = app(SkipPolicy::builder()
+        .gamepad_buttons(vec![GamepadButtonType::South])
+        .build());
+    run_until_running(&mut app);
+
+    press_gamepad_button(&mut app, GamepadButtonType::South);
+    skip_frames(&mut app);
+
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn other_gamepad_button_does_not_skip_intro() {
+    let mut app = app(SkipPolicy::builder()
+        .gamepad_buttons(vec![GamepadButtonType::South])
+        .build());
+    run_until_running(&mut app);
+
+    press_gamepad_button(&mut app, GamepadButtonType::North);
+    skip_frames(&mut app);
+
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+}
+
+#[test]
+fn tap_skips_intro() {
+    let mut app = app(SkipPolicy::builder().touch(true).build());
+    run_until_running(&mut app);
+
+    tap(&mut app);
+    skip_frames(&mut app);
+
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn tap_does_not_skip_intro_without_touch() {
+    let mut app = app(SkipPolicy::default());
+    run_until_running(&mut app);
+
+    tap(&mut app);
+    skip_frames(&mut app);
+
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+}
+
+#[test]
+fn skipping_sends_intro_skipped() {
+    let mut app = app(SkipPolicy::builder().touch(true).build());
+    run_until_running(&mut app);
+
+    tap(&mut app);
+    skip_frames(&mut app);
+
+    let skipped = app.world().resource::<Events<IntroSkipped>>();
+    let inputs: Vec<_> = skipped.iter_current_update_events().map(|event| event.input).collect();
+    assert_eq!(inputs, [SkipInput::Touch]);
+
+    app.update();
+    assert_eq!(
+        *app.world().resource::<State<IntroState>>().get(),
+        IntroState::Idle
+    );
+}
+
+#[test]
+fn builder_defaults_to_the_default_policy() {
+    assert_eq!(SkipPolicy::builder().build(), SkipPolicy::default());
+    assert!(!SkipPolicy::builder().build().is_disabled());
+}
+
+fn hold_app() -> App {
+    let mut app = app(SkipPolicy::builder()
+        .keys(vec![KeyCode::Space])
+        .hold(Duration::from_secs(1))
+        .build());
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    run_until_running(&mut app);
+    app
+}
+
+#[test]
+fn holding_for_less_than_hold_does_not_skip_intro() {
+    let mut app = hold_app();
+
+    key(&mut app, KeyCode::Space, ButtonState::Pressed);
+    update(&mut app, 5);
+    assert!(hold_progress(&app).is_holding());
+    assert!(hold_progress(&app).fraction() > 0.0 && hold_progress(&app).fraction() < 1.0);
+
+    key(&mut app, KeyCode::Space, ButtonState::Released);
+    update(&mut app, 20);
+
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+}
+
+#[test]
+fn holding_for_longer_than_hold_skips_intro() {
+    let mut app = hold_app();
+
+    key(&mut app, KeyCode::Space, ButtonState::Pressed);
+    update(&mut app, 5);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    update(&mut app, 10);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn holding_while_paused_does_not_skip_intro() {
+    let mut app = hold_app();
+    app.world_mut().resource_mut::<IntroTime>().pause();
+
+    key(&mut app, KeyCode::Space, ButtonState::Pressed);
+    update(&mut app, 20);
+    assert_eq!(hold_progress(&app).fraction(), 0.0);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    app.world_mut().resource_mut::<IntroTime>().resume();
+    update(&mut app, 15);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn releasing_resets_hold_progress() {
+    let mut app = hold_app();
+
+    key(&mut app, KeyCode::Space, ButtonState::Pressed);
+    update(&mut app, 5);
+    key(&mut app, KeyCode::Space, ButtonState::Released);
+    update(&mut app, 1);
+
+    assert!(!hold_progress(&app).is_holding());
+    assert_eq!(hold_progress(&app).fraction(), 0.0);
+
+    // The next hold starts over instead of carrying on
+    key(&mut app, KeyCode::Space, ButtonState::Pressed);
+    update(&mut app, 7);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+}
+
+fn can_skip_now(app: &mut App) -> bool {
+    app.world_mut().run_system_once(can_skip)
+}
+
+#[test]
+fn can_not_skip_when_skipping_is_disabled() {
+    let mut app = app(SkipPolicy::disabled());
+    run_until_running(&mut app);
+
+    assert!(!can_skip_now(&mut app));
+}
+
+#[test]
+fn unskippable_window_keeps_intro_from_being_skipped() {
+    let preferences = IntroPreferences::builder()
+        .run_at(AppState::SplashScreen)
+        .transition_to(AppState::GameMenu)
+        .skip_policy(SkipPolicy::builder().touch(true).build())
+        .unskippable_for(Duration::from_secs(1))
+        .duration(FixedDuration::new_with_duration(
+            Duration::from_secs(60),
+            AppState::GameMenu,
+        ))
+        .ui(HeadlessIntroScreen)
+        .build();
+    let mut app = common::app(preferences);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    run_until_running(&mut app);
+
+    assert!(!can_skip_now(&mut app));
+    assert!(!app.world().resource::<UnskippableWindow>().is_closed());
+
+    tap(&mut app);
+    skip_frames(&mut app);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    update(&mut app, 10);
+    assert!(can_skip_now(&mut app));
+    assert_eq!(
+        app.world().resource::<UnskippableWindow>().remaining(),
+        Duration::ZERO
+    );
+
+    tap(&mut app);
+    skip_frames(&mut app);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn skip_on_input_picks_a_skip_policy() {
+    let preferences = |skip_on_input: Option<bool>| {
+        IntroPreferences::builder()
+            .run_at(AppState::SplashScreen)
+            .transition_to(AppState::GameMenu)
+            .maybe_skip_on_input(skip_on_input)
+            .duration(FixedDuration::new(AppState::GameMenu))
+            .ui(HeadlessIntroScreen)
+            .build()
+    };
+
+    assert_eq!(
+        *preferences(Some(true)).skip_policy(),
+        SkipPolicy::default()
+    );
+    assert_eq!(
+        *preferences(Some(false)).skip_policy(),
+        SkipPolicy::disabled()
+    );
+    assert_eq!(*preferences(None).skip_policy(), SkipPolicy::default());
+}
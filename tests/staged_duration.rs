@@ -0,0 +1,128 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_intro_screen::prelude::*;
+use common::{app_state, preferences, run_until_running, AppState};
+
+fn stages(app: &mut App) -> Mut<'_, IntroStages> {
+    app.world_mut().resource_mut::<IntroStages>()
+}
+
+fn progress(app: &App) -> (Option<f32>, Option<String>) {
+    let progress = app.world().resource::<IntroProgress>();
+    (progress.fraction(), progress.message().map(String::from))
+}
+
+#[test]
+fn stages_report_weighted_progress() {
+    let duration = StagedDuration::new(Duration::from_secs(60))
+        .with_stage("Compiling shaders", 1.0)
+        .with_stage("Loading audio", 3.0);
+
+    let mut app = common::app(preferences(duration));
+    run_until_running(&mut app);
+    app.update();
+    assert_eq!(
+        progress(&app),
+        (Some(0.0), Some(String::from("Compiling shaders")))
+    );
+
+    stages(&mut app).complete("Compiling shaders");
+    app.update();
+    assert_eq!(
+        progress(&app),
+        (Some(0.25), Some(String::from("Loading audio")))
+    );
+
+    stages(&mut app).set_progress("Loading audio", 0.5);
+    app.update();
+    assert_eq!(
+        progress(&app),
+        (Some(0.625), Some(String::from("Loading audio")))
+    );
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    stages(&mut app).complete("Loading audio");
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn partly_complete_stages_count_before_the_current_one_completes() {
+    let duration = StagedDuration::new(Duration::from_secs(60))
+        .with_stage("Compiling shaders", 1.0)
+        .with_stage("Loading audio", 3.0);
+
+    let mut app = common::app(preferences(duration));
+    run_until_running(&mut app);
+
+    stages(&mut app).set_progress("Compiling shaders", 0.5);
+    stages(&mut app).set_progress("Loading audio", 0.5);
+    app.update();
+    assert_eq!(
+        progress(&app),
+        (Some(0.5), Some(String::from("Compiling shaders")))
+    );
+    assert_eq!(
+        stages(&mut app).current().map(IntroStage::name),
+        Some("Compiling shaders")
+    );
+}
+
+#[test]
+fn message_is_only_set_when_the_stage_changes() {
+    let duration = StagedDuration::new(Duration::from_secs(60))
+        .with_stage("Compiling shaders", 1.0)
+        .with_stage("Loading audio", 1.0);
+
+    let mut app = common::app(preferences(duration));
+    run_until_running(&mut app);
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<IntroProgress>()
+        .set_message("Compiling 12 shaders");
+    stages(&mut app).set_progress("Compiling shaders", 0.5);
+    app.update();
+    assert_eq!(
+        progress(&app),
+        (Some(0.25), Some(String::from("Compiling 12 shaders")))
+    );
+
+    stages(&mut app).complete("Compiling shaders");
+    app.update();
+    assert_eq!(
+        progress(&app),
+        (Some(0.5), Some(String::from("Loading audio")))
+    );
+}
+
+#[test]
+fn stages_completed_when_entering_run_at_are_kept() {
+    let duration = StagedDuration::new(Duration::from_secs(60))
+        .with_stage("Compiling shaders", 1.0)
+        .with_stage("Loading audio", 1.0);
+
+    let mut app = common::app(preferences(duration));
+    app.add_systems(
+        OnEnter(AppState::SplashScreen),
+        complete_stage::<()>("Compiling shaders"),
+    );
+    run_until_running(&mut app);
+    app.update();
+    assert_eq!(
+        progress(&app),
+        (Some(0.5), Some(String::from("Loading audio")))
+    );
+
+    stages(&mut app).complete("Loading audio");
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(app_state(&app), AppState::GameMenu);
+    assert!(stages(&mut app).iter().all(|stage| !stage.is_completed()));
+}
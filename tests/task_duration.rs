@@ -0,0 +1,86 @@
+#![cfg(feature = "tasks")]
+
+mod common;
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use bevy::prelude::*;
+use bevy_intro_screen::prelude::*;
+use common::{app_state, preferences, record_failures, run_until_running, AppState, Failed};
+
+fn app(duration: TaskDuration) -> App {
+    let mut app = common::app(preferences(duration));
+    record_failures(&mut app);
+    app
+}
+
+// The tasks run on other threads, so give them some real time to finish
+fn update_until(app: &mut App, done: impl Fn(&App) -> bool) -> bool {
+    for _ in 0..200 {
+        app.update();
+        if done(app) {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+
+    false
+}
+
+#[test]
+fn intro_waits_for_every_task() {
+    let released = Arc::new(AtomicBool::new(false));
+    let waiting = released.clone();
+
+    let duration = TaskDuration::new(Duration::from_secs(60))
+        .with_blocking_task("instant", |_| Ok(()))
+        .with_task("waiting", move |progress| {
+            let waiting = waiting.clone();
+            async move {
+                progress.set(0.5);
+                while !waiting.load(Ordering::Relaxed) {
+                    std::thread::sleep(Duration::from_millis(1));
+                }
+                Ok(())
+            }
+        });
+
+    let mut app = app(duration);
+    run_until_running(&mut app);
+
+    assert!(update_until(&mut app, |app| {
+        app.world().resource::<IntroProgress>().fraction() == Some(0.75)
+    }));
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    let tasks = app.world().resource::<IntroTasks>();
+    let states: Vec<_> = tasks.iter().map(|task| (task.name(), task.state().clone())).collect();
+    assert_eq!(
+        states,
+        [
+            ("instant", IntroTaskState::Completed),
+            ("waiting", IntroTaskState::Running)
+        ]
+    );
+
+    released.store(true, Ordering::Relaxed);
+    assert!(update_until(&mut app, |app| app_state(app) == AppState::GameMenu));
+    assert!(!app.world().resource::<Failed>().0);
+}
+
+#[test]
+fn failing_task_fails_the_intro() {
+    let duration = TaskDuration::new(Duration::from_secs(60))
+        .with_blocking_task("broken", |_| Err(String::from("the save file is corrupted")));
+
+    let mut app = app(duration);
+    run_until_running(&mut app);
+
+    assert!(update_until(&mut app, |app| app.world().resource::<Failed>().0));
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy, window::PrimaryWindow};
+use bevy_intro_screen::prelude::*;
+use common::{app_state, run_until_running, update, AppState, HeadlessIntroScreen, FRAME};
+
+fn app(time_source: IntroTimeSource, pause_when_unfocused: bool) -> App {
+    let preferences = IntroPreferences::builder()
+        .run_at(AppState::SplashScreen)
+        .transition_to(AppState::GameMenu)
+        .skip_policy(SkipPolicy::disabled())
+        .duration(FixedDuration::new_with_duration(
+            Duration::from_secs(1),
+            AppState::GameMenu,
+        ))
+        .ui(HeadlessIntroScreen)
+        .time_source(time_source)
+        .pause_when_unfocused(pause_when_unfocused)
+        .build();
+
+    let mut app = common::app(preferences);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+fn intro_time(app: &mut App) -> Mut<'_, IntroTime> {
+    app.world_mut().resource_mut::<IntroTime>()
+}
+
+#[test]
+fn paused_intro_waits_until_resumed() {
+    let mut app = app(IntroTimeSource::Virtual, false);
+    run_until_running(&mut app);
+
+    intro_time(&mut app).pause();
+    update(&mut app, 30);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    intro_time(&mut app).resume();
+    update(&mut app, 12);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn extended_intro_runs_longer() {
+    let mut app = app(IntroTimeSource::Virtual, false);
+    run_until_running(&mut app);
+
+    intro_time(&mut app).extend(Duration::from_secs(1));
+    update(&mut app, 15);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    update(&mut app, 7);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn speed_scales_the_countdown() {
+    let mut app = app(IntroTimeSource::Virtual, false);
+    run_until_running(&mut app);
+
+    intro_time(&mut app).set_speed(2.0);
+    update(&mut app, 7);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn non_finite_speeds_are_clamped() {
+    let mut app = app(IntroTimeSource::Virtual, false);
+    run_until_running(&mut app);
+
+    intro_time(&mut app).set_speed(f32::NAN);
+    assert_eq!(intro_time(&mut app).speed(), 0.0);
+    update(&mut app, 30);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    intro_time(&mut app).set_speed(f32::INFINITY);
+    assert_eq!(intro_time(&mut app).speed(), IntroTime::<()>::MAX_SPEED);
+    update(&mut app, 2);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn unfocused_window_pauses_the_intro() {
+    let mut app = app(IntroTimeSource::Virtual, true);
+    let window = app
+        .world_mut()
+        .spawn((
+            Window {
+                focused: false,
+                ..Default::default()
+            },
+            PrimaryWindow,
+        ))
+        .id();
+
+    run_until_running(&mut app);
+    update(&mut app, 30);
+    assert_eq!(app_state(&app), AppState::SplashScreen);
+
+    app.world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .focused = true;
+    update(&mut app, 12);
+    assert_eq!(app_state(&app), AppState::GameMenu);
+}
+
+#[test]
+fn real_time_ignores_paused_virtual_time() {
+    let mut virtual_app = app(IntroTimeSource::Virtual, false);
+    let mut real_app = app(IntroTimeSource::Real, false);
+
+    for app in [&mut virtual_app, &mut real_app] {
+        run_until_running(app);
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        update(app, 15);
+    }
+
+    assert_eq!(app_state(&virtual_app), AppState::SplashScreen);
+    assert_eq!(app_state(&real_app), AppState::GameMenu);
+}